@@ -74,6 +74,7 @@ pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponen
 		inherent_data_providers.clone(),
 		&task_manager.spawn_handle(),
 		config.prometheus_registry(),
+		sc_consensus_aura::SlotHeaderPruning::SlotDistance,
 		sp_consensus::CanAuthorWithNativeVersion::new(client.executor().clone()),
 	)?;
 
@@ -201,6 +202,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 			force_authoring,
 			backoff_authoring_blocks,
 			keystore_container.sync_keystore(),
+			sc_consensus_aura::SlotHeaderPruning::SlotDistance,
 			can_author_with,
 		)?;
 
@@ -292,6 +294,7 @@ pub fn new_light(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		InherentDataProviders::new(),
 		&task_manager.spawn_handle(),
 		config.prometheus_registry(),
+		sc_consensus_aura::SlotHeaderPruning::SlotDistance,
 		sp_consensus::NeverCanAuthor,
 	)?;
 
@@ -76,4 +76,7 @@ pub enum Subcommand {
 
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
+
+	/// Check the block headers and number index of the database for inconsistencies.
+	DbCheck(sc_cli::DbCheckCmd),
 }
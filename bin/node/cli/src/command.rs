@@ -149,5 +149,13 @@ pub fn run() -> Result<()> {
 				Ok((cmd.run(client, backend), task_manager))
 			})
 		},
+		Some(Subcommand::DbCheck(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { task_manager, backend, ..}
+					= new_partial(&config)?;
+				Ok((cmd.run(backend), task_manager))
+			})
+		},
 	}
 }
@@ -57,7 +57,10 @@ pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponen
 			grandpa::LinkHalf<Block, FullClient, FullSelectChain>,
 			sc_consensus_babe::BabeLink<Block>,
 		),
-		grandpa::SharedVoterState,
+		(
+			grandpa::SharedVoterState,
+			Arc<grandpa::FinalityProofProvider<FullBackend, Block>>,
+		),
 	)
 >, ServiceError> {
 	let (client, backend, keystore_container, task_manager) =
@@ -83,6 +86,7 @@ pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponen
 		sc_consensus_babe::Config::get_or_compute(&*client)?,
 		grandpa_block_import,
 		client.clone(),
+		sc_consensus_babe::SlotHeaderPruning::SlotDistance,
 	)?;
 
 	let inherent_data_providers = sp_inherents::InherentDataProviders::new();
@@ -107,12 +111,12 @@ pub fn new_partial(config: &Configuration) -> Result<sc_service::PartialComponen
 		let justification_stream = grandpa_link.justification_stream();
 		let shared_authority_set = grandpa_link.shared_authority_set().clone();
 		let shared_voter_state = grandpa::SharedVoterState::empty();
-		let rpc_setup = shared_voter_state.clone();
 
 		let finality_proof_provider = grandpa::FinalityProofProvider::new_for_service(
 			backend.clone(),
 			Some(shared_authority_set.clone()),
 		);
+		let rpc_setup = (shared_voter_state.clone(), finality_proof_provider.clone());
 
 		let babe_config = babe_link.config().clone();
 		let shared_epoch_changes = babe_link.epoch_changes().clone();
@@ -192,10 +196,19 @@ pub fn new_full_base(
 		other: (rpc_extensions_builder, import_setup, rpc_setup),
 	} = new_partial(&config)?;
 
-	let shared_voter_state = rpc_setup;
+	let (shared_voter_state, finality_proof_provider) = rpc_setup;
 
 	config.network.extra_sets.push(grandpa::grandpa_peers_set_config());
 
+	config.network.request_response_protocols.push({
+		let (handler, protocol_config) = grandpa::FinalityProofRequestHandler::new(
+			&config.protocol_id(),
+			finality_proof_provider,
+		);
+		task_manager.spawn_handle().spawn("grandpa-finality-proof-request-handler", handler.run());
+		protocol_config
+	});
+
 	#[cfg(feature = "cli")]
 	config.network.request_response_protocols.push(sc_finality_grandpa_warp_sync::request_response_config_for_chain(
 		&config, task_manager.spawn_handle(), backend.clone(),
@@ -390,6 +403,7 @@ pub fn new_light_base(mut config: Configuration) -> Result<(
 		sc_consensus_babe::Config::get_or_compute(&*client)?,
 		grandpa_block_import,
 		client.clone(),
+		sc_consensus_babe::SlotHeaderPruning::SlotDistance,
 	)?;
 
 	let inherent_data_providers = sp_inherents::InherentDataProviders::new();
@@ -24,7 +24,7 @@ use sp_runtime::{
 	generic::BlockId, traits::{Block as BlockT, HashFor},
 };
 use sp_state_machine::{
-	OverlayedChanges, ExecutionManager, ExecutionStrategy, StorageProof,
+	OverlayedChanges, ExecutionManager, ExecutionStrategy, StorageProof, StorageCollection,
 };
 use sc_executor::{RuntimeVersion, NativeVersion};
 use sp_externalities::Extensions;
@@ -65,6 +65,21 @@ pub trait CallExecutor<B: BlockT> {
 		extensions: Option<Extensions>,
 	) -> Result<Vec<u8>, sp_blockchain::Error>;
 
+	/// Execute a call to a contract on top of state in a block of given hash, with the given
+	/// storage `overrides` applied on top of that state.
+	///
+	/// No changes are made. Returns the result of the call along with all top-level storage
+	/// changes, including the overrides. Overriding `:code` executes the given runtime.
+	fn call_with_overrides(
+		&self,
+		id: &BlockId<B>,
+		method: &str,
+		call_data: &[u8],
+		overrides: StorageCollection,
+		strategy: ExecutionStrategy,
+		extensions: Option<Extensions>,
+	) -> Result<(Vec<u8>, StorageCollection), sp_blockchain::Error>;
+
 	/// Execute a contextual call on top of state in a block of a given hash.
 	///
 	/// No changes are made.
@@ -26,6 +26,7 @@ use sp_runtime::{
 	Justification,
 };
 use sp_consensus::BlockOrigin;
+use sp_state_machine::{StorageCollection, ChildStorageCollection};
 
 use crate::blockchain::Info;
 use crate::notifications::StorageEventStream;
@@ -254,6 +255,10 @@ pub struct BlockImportNotification<Block: BlockT> {
 	///
 	/// If `None`, there was no re-org while importing.
 	pub tree_route: Option<Arc<sp_blockchain::TreeRoute<Block>>>,
+	/// Storage changes applied by the imported block.
+	///
+	/// `None` if the block was imported without executing it, e.g. during fast sync.
+	pub storage_changes: Option<Arc<(StorageCollection, ChildStorageCollection)>>,
 }
 
 /// Summary of a finalized block.
@@ -43,17 +43,34 @@ impl AddrCache {
 			return;
 		}
 
+		addresses.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+		// Insert into `self.authority_id_to_addresses`.
+		let old_addresses = self.authority_id_to_addresses.insert(
+			authority_id.clone(),
+			addresses.clone(),
+		);
+
+		// Remove the [`PeerId`]s that the authority no longer advertises, so that they no longer
+		// resolve to `authority_id`.
+		let old_peer_ids = old_addresses.iter()
+			.flatten()
+			.filter(|a| !addresses.contains(a))
+			.map(|a| peer_id_from_multiaddr(a))
+			.filter_map(|peer_id| peer_id);
+		for peer_id in old_peer_ids {
+			if self.peer_id_to_authority_id.get(&peer_id) == Some(&authority_id) {
+				self.peer_id_to_authority_id.remove(&peer_id);
+			}
+		}
+
 		// Insert into `self.peer_id_to_authority_id`.
 		let peer_ids = addresses.iter()
 			.map(|a| peer_id_from_multiaddr(a))
 			.filter_map(|peer_id| peer_id);
-		for peer_id in  peer_ids {
+		for peer_id in peer_ids {
 			self.peer_id_to_authority_id.insert(peer_id, authority_id.clone());
 		}
-
-		// Insert into `self.authority_id_to_addresses`.
-		addresses.sort_unstable_by(|a, b| a.as_ref().cmp(b.as_ref()));
-		self.authority_id_to_addresses.insert(authority_id, addresses);
 	}
 
 	/// Returns the number of authority IDs in the cache.
@@ -190,4 +207,48 @@ mod tests {
 			.max_tests(10)
 			.quickcheck(property as fn(_, _, _) -> TestResult)
 	}
+
+	#[test]
+	fn removes_peer_ids_no_longer_advertised_on_insert() {
+		fn property(
+			authority: TestAuthorityId,
+			first: TestMultiaddr,
+			second: TestMultiaddr,
+		) -> TestResult {
+			let authority: AuthorityId = authority.0;
+			let first: Multiaddr = first.0;
+			let second: Multiaddr = second.0;
+
+			if first == second {
+				return TestResult::discard();
+			}
+
+			let mut cache = AddrCache::new();
+
+			cache.insert(authority.clone(), vec![first.clone()]);
+			cache.insert(authority.clone(), vec![second.clone()]);
+
+			assert_eq!(
+				Some(&vec![second.clone()]),
+				cache.get_addresses_by_authority_id(&authority),
+				"Expect `get_addresses_by_authority_id` to return the latest addresses."
+			);
+			assert_eq!(
+				None,
+				cache.get_authority_id_by_peer_id(&peer_id_from_multiaddr(&first).unwrap()),
+				"Expect `get_authority_id_by_peer_id` to return `None` for the replaced address."
+			);
+			assert_eq!(
+				Some(&authority),
+				cache.get_authority_id_by_peer_id(&peer_id_from_multiaddr(&second).unwrap()),
+				"Expect `get_authority_id_by_peer_id` to return the authority for the new address."
+			);
+
+			TestResult::passed()
+		}
+
+		QuickCheck::new()
+			.max_tests(10)
+			.quickcheck(property as fn(_, _, _) -> TestResult)
+	}
 }
@@ -328,6 +328,7 @@ impl<A, B, Block, C> Proposer<B, Block, C, A>
 		self.metrics.report(
 			|metrics| {
 				metrics.number_of_transactions.set(block.extrinsics().len() as u64);
+				metrics.number_of_skipped_transactions.set(skipped as u64);
 				metrics.block_constructed.observe(block_timer.elapsed().as_secs_f64());
 			}
 		);
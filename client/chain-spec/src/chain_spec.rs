@@ -225,6 +225,11 @@ impl<G, E> ChainSpec<G, E> {
 		&self.client_spec.extensions
 	}
 
+	/// Returns a mutable reference to defined chain spec extensions.
+	pub fn extensions_mut(&mut self) -> &mut E {
+		&mut self.client_spec.extensions
+	}
+
 	/// Create hardcoded spec.
 	pub fn from_genesis<F: Fn() -> G + 'static + Send + Sync>(
 		name: &str,
@@ -376,6 +381,10 @@ where
 		ChainSpec::extensions(self) as &dyn GetExtension
 	}
 
+	fn extensions_mut(&mut self) -> &mut dyn GetExtension {
+		ChainSpec::extensions_mut(self) as &mut dyn GetExtension
+	}
+
 	fn as_json(&self, raw: bool) -> Result<String, String> {
 		ChainSpec::as_json(self, raw)
 	}
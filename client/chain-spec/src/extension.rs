@@ -126,6 +126,8 @@ pub trait Extension: Serialize + DeserializeOwned + Clone {
 	fn get<T: 'static>(&self) -> Option<&T>;
 	/// Get an extension of specific type as refernce to `Any`
 	fn get_any(&self, t: TypeId) -> &dyn Any;
+	/// Get an extension of specific type as mutable refernce to `Any`
+	fn get_any_mut(&mut self, t: TypeId) -> &mut dyn Any;
 
 	/// Get forkable extensions of specific type.
 	fn forks<BlockNumber, T>(&self) -> Option<Forks<BlockNumber, T>> where
@@ -144,6 +146,7 @@ impl Extension for crate::NoExtension {
 
 	fn get<T: 'static>(&self) -> Option<&T> { None }
 	fn get_any(&self, _t: TypeId) -> &dyn Any { self }
+	fn get_any_mut(&mut self, _: TypeId) -> &mut dyn Any { self }
 }
 
 pub trait IsForks {
@@ -245,6 +248,13 @@ impl<B, E> Extension for Forks<B, E> where
 		}
 	}
 
+	fn get_any_mut(&mut self, t: TypeId) -> &mut dyn Any {
+		match t {
+			x if x == TypeId::of::<E>() => &mut self.base,
+			_ => self.base.get_any_mut(t),
+		}
+	}
+
 	fn forks<BlockNumber, T>(&self) -> Option<Forks<BlockNumber, T>> where
 		BlockNumber: Ord + Clone + 'static,
 		T: Group + 'static,
@@ -264,12 +274,19 @@ impl<B, E> Extension for Forks<B, E> where
 pub trait GetExtension {
 	/// Get an extension of specific type.
 	fn get_any(&self, t: TypeId) -> &dyn Any;
+
+	/// Get an extension of specific type with mutable access.
+	fn get_any_mut(&mut self, t: TypeId) -> &mut dyn Any;
 }
 
 impl <E: Extension> GetExtension for E {
 	fn get_any(&self, t: TypeId) -> &dyn Any {
 		Extension::get_any(self, t)
 	}
+
+	fn get_any_mut(&mut self, t: TypeId) -> &mut dyn Any {
+		Extension::get_any_mut(self, t)
+	}
 }
 
 /// Helper function that queries an extension by type from `GetExtension`
@@ -278,6 +295,12 @@ pub fn get_extension<T: 'static>(e: &dyn GetExtension) -> Option<&T> {
 	Any::downcast_ref(GetExtension::get_any(e, TypeId::of::<T>()))
 }
 
+/// Helper function that queries an extension by type from `GetExtension`
+/// trait object with mutable access.
+pub fn get_extension_mut<T: 'static>(e: &mut dyn GetExtension) -> Option<&mut T> {
+	Any::downcast_mut(GetExtension::get_any_mut(e, TypeId::of::<T>()))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -392,4 +415,18 @@ mod tests {
 		let ext2_3 = ext.forks::<u64, Extension2>().unwrap();
 		assert_eq!(ext2_2, ext2_3);
 	}
+
+	#[test]
+	fn get_extension_mut_should_work() {
+		let mut ext = Extensions {
+			ext1: Extension1 { test: 1 },
+			ext2: Extension2 { test: 2 },
+		};
+
+		get_extension_mut::<Extension2>(&mut ext).unwrap().test = 42;
+		assert!(get_extension_mut::<Ext2>(&mut ext).is_none());
+
+		assert_eq!(get_extension::<Extension1>(&ext), Some(&Extension1 { test: 1 }));
+		assert_eq!(get_extension::<Extension2>(&ext), Some(&Extension2 { test: 42 }));
+	}
 }
@@ -113,7 +113,7 @@ mod extension;
 pub use chain_spec::{
 	ChainSpec as GenericChainSpec, NoExtension, LightSyncState, SerializableLightSyncState,
 };
-pub use extension::{Group, Fork, Forks, Extension, GetExtension, get_extension};
+pub use extension::{Group, Fork, Forks, Extension, GetExtension, get_extension, get_extension_mut};
 pub use sc_chain_spec_derive::{ChainSpecExtension, ChainSpecGroup};
 pub use sp_chain_spec::{Properties, ChainType};
 
@@ -147,6 +147,8 @@ pub trait ChainSpec: BuildStorage + Send + Sync {
 	fn properties(&self) -> Properties;
 	/// Returns a reference to defined chain spec extensions.
 	fn extensions(&self) -> &dyn GetExtension;
+	/// Returns a mutable reference to defined chain spec extensions.
+	fn extensions_mut(&mut self) -> &mut dyn GetExtension;
 	/// Add a bootnode to the list.
 	fn add_boot_node(&mut self, addr: MultiaddrWithPeerId);
 	/// Return spec as JSON.
@@ -0,0 +1,61 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error;
+use crate::params::{PruningParams, SharedParams};
+use crate::CliConfiguration;
+use sc_service::chain_ops::check_database;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use structopt::StructOpt;
+use sc_client_api::Backend;
+
+/// The `db-check` command used to check the block headers and the block number index of the
+/// database for inconsistencies.
+#[derive(Debug, StructOpt)]
+pub struct DbCheckCmd {
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+}
+
+impl DbCheckCmd {
+	/// Run the db-check command
+	pub async fn run<B, BA>(&self, backend: Arc<BA>) -> error::Result<()>
+	where
+		B: BlockT,
+		BA: Backend<B>,
+	{
+		check_database(backend)?;
+		Ok(())
+	}
+}
+
+impl CliConfiguration for DbCheckCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+}
@@ -17,6 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 mod build_spec_cmd;
 mod check_block_cmd;
+mod db_check_cmd;
 mod export_blocks_cmd;
 mod export_state_cmd;
 mod import_blocks_cmd;
@@ -37,6 +38,7 @@ pub mod utils;
 pub use self::{
 	build_spec_cmd::BuildSpecCmd,
 	check_block_cmd::CheckBlockCmd,
+	db_check_cmd::DbCheckCmd,
 	export_blocks_cmd::ExportBlocksCmd,
 	export_state_cmd::ExportStateCmd,
 	import_blocks_cmd::ImportBlocksCmd,
@@ -26,6 +26,9 @@ use std::io::{self, Write};
 use structopt::StructOpt;
 
 /// The `purge-chain` command used to remove the whole chain.
+///
+/// Only the database of the selected chain is removed, which includes the consensus auxiliary
+/// data. The keystore and the network identity key live outside of it and are kept.
 #[derive(Debug, StructOpt)]
 pub struct PurgeChainCmd {
 	/// Skip interactive prompt by answering yes automatically.
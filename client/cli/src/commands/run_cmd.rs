@@ -32,6 +32,7 @@ use sc_service::{
 };
 use sc_telemetry::TelemetryEndpoints;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
 use structopt::StructOpt;
 
 /// The `run` command used to run a node.
@@ -139,6 +140,19 @@ pub struct RunCmd {
 	#[structopt(long = "ws-max-connections", value_name = "COUNT")]
 	pub ws_max_connections: Option<usize>,
 
+	/// Maximum size of HTTP & WS RPC requests, in megabytes. Default is 15.
+	///
+	/// Larger requests are rejected. Responses are not limited.
+	#[structopt(long = "rpc-max-payload", value_name = "MEGABYTES")]
+	pub rpc_max_payload: Option<usize>,
+
+	/// Maximum age of the best block, in seconds, for the node to report itself ready.
+	///
+	/// Once the last new best block was imported longer ago, `system_ready` and the HTTP
+	/// `/ready` endpoint fail. By default the best block age isn't checked.
+	#[structopt(long = "rpc-ready-max-block-age", value_name = "SECONDS")]
+	pub rpc_ready_max_block_age: Option<u64>,
+
 	/// Specify browser Origins allowed to access the HTTP & WS RPC servers.
 	///
 	/// A comma-separated list of origins (protocol://domain or special `null`
@@ -363,6 +377,13 @@ impl CliConfiguration for RunCmd {
 		let is_light = self.light;
 		let is_authority = (self.validator || is_dev || keyring.is_some()) && !is_light;
 
+		if is_authority && !self.sentry.is_empty() {
+			return Err(Error::Input(
+				"`--sentry` can not be used together with a validator role, which is implied \
+				 by `--dev` and the development account flags (e.g. `--alice`)".into()
+			));
+		}
+
 		Ok(if is_light {
 			sc_service::Role::Light
 		} else if is_authority {
@@ -410,6 +431,14 @@ impl CliConfiguration for RunCmd {
 		Ok(self.ws_max_connections)
 	}
 
+	fn rpc_max_payload(&self) -> Result<Option<usize>> {
+		Ok(self.rpc_max_payload)
+	}
+
+	fn rpc_ready_max_block_age(&self) -> Result<Option<Duration>> {
+		Ok(self.rpc_ready_max_block_age.map(Duration::from_secs))
+	}
+
 	fn rpc_cors(&self, is_dev: bool) -> Result<Option<Vec<String>>> {
 		Ok(self
 			.rpc_cors
@@ -623,4 +652,16 @@ mod tests {
 		assert!(is_node_name_valid("www.visit.me").is_err());
 		assert!(is_node_name_valid("email@domain").is_err());
 	}
+
+	#[test]
+	fn sentry_conflicts_with_implied_validator_role() {
+		let sentry = "/ip4/127.0.0.1/tcp/30333/p2p/QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV";
+
+		let cmd = RunCmd::from_iter(&["test", "--sentry", sentry]);
+		assert!(matches!(cmd.role(false), Ok(sc_service::Role::Sentry { .. })));
+
+		let cmd = RunCmd::from_iter(&["test", "--alice", "--sentry", sentry]);
+		assert!(cmd.role(false).is_err());
+		assert!(cmd.role(true).is_err());
+	}
 }
@@ -36,6 +36,7 @@ use sc_service::{ChainSpec, TracingReceiver, KeepBlocks, TransactionStorageMode}
 use sc_telemetry::{TelemetryHandle, TelemetrySpan};
 use sc_tracing::logging::LoggerBuilder;
 use std::net::SocketAddr;
+use std::time::Duration;
 use std::path::PathBuf;
 
 /// The maximum number of characters for a node name.
@@ -306,6 +307,16 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			.unwrap_or_default()
 	}
 
+	/// Get the encoded hashes of blocks which must not be imported.
+	///
+	/// By default this is retrieved from `ImportParams` if it is available. Otherwise it's
+	/// empty.
+	fn banned_blocks(&self) -> Result<Vec<Vec<u8>>> {
+		Ok(self.import_params()
+			.map(|x| x.banned_blocks())
+			.unwrap_or_default())
+	}
+
 	/// Get the execution strategies.
 	///
 	/// By default this is retrieved from `ImportParams` if it is available. Otherwise its
@@ -357,6 +368,20 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(None)
 	}
 
+	/// Get the maximum RPC payload in megabytes (`None` if default).
+	///
+	/// By default this is `None`.
+	fn rpc_max_payload(&self) -> Result<Option<usize>> {
+		Ok(None)
+	}
+
+	/// Get the maximum age of the best block for the node to be ready (`None` if not checked).
+	///
+	/// By default this is `None`.
+	fn rpc_ready_max_block_age(&self) -> Result<Option<Duration>> {
+		Ok(None)
+	}
+
 	/// Get the RPC cors (`None` if disabled)
 	///
 	/// By default this is `Some(Vec::new())`.
@@ -525,12 +550,15 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			transaction_storage: self.database_transaction_storage()?,
 			wasm_method: self.wasm_method()?,
 			wasm_runtime_overrides: self.wasm_runtime_overrides(),
+			banned_blocks: self.banned_blocks()?,
 			execution_strategies: self.execution_strategies(is_dev, is_validator)?,
 			rpc_http: self.rpc_http(DCV::rpc_http_listen_port())?,
 			rpc_ws: self.rpc_ws(DCV::rpc_ws_listen_port())?,
 			rpc_ipc: self.rpc_ipc()?,
 			rpc_methods: self.rpc_methods()?,
 			rpc_ws_max_connections: self.rpc_ws_max_connections()?,
+			rpc_max_payload: self.rpc_max_payload()?,
+			rpc_ready_max_block_age: self.rpc_ready_max_block_age()?,
 			rpc_cors: self.rpc_cors(is_dev)?,
 			prometheus_config: self.prometheus_config(DCV::prometheus_listen_port())?,
 			telemetry_endpoints,
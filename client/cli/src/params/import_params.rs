@@ -62,6 +62,18 @@ pub struct ImportParams {
 	#[structopt(long, value_name = "PATH", parse(from_os_str))]
 	pub wasm_runtime_overrides: Option<PathBuf>,
 
+	/// Refuse to import the block with the given 32 byte hex encoded hash and any of its
+	/// descendants.
+	///
+	/// Can be given multiple times. Use with caution, this overrides the consensus
+	/// of the network.
+	#[structopt(
+		long = "unsafe-force-ban-block",
+		value_name = "HASH",
+		parse(try_from_str = parse_block_hash)
+	)]
+	pub unsafe_force_ban_block: Vec<Vec<u8>>,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub execution_strategies: ExecutionStrategiesParams,
@@ -93,6 +105,11 @@ impl ImportParams {
 		self.wasm_runtime_overrides.clone()
 	}
 
+	/// Encoded hashes of blocks that should never be imported.
+	pub fn banned_blocks(&self) -> Vec<Vec<u8>> {
+		self.unsafe_force_ban_block.clone()
+	}
+
 	/// Get execution strategies for the parameters
 	pub fn execution_strategies(&self, is_dev: bool, is_validator: bool) -> ExecutionStrategies {
 		let exec = &self.execution_strategies;
@@ -190,3 +207,34 @@ pub struct ExecutionStrategiesParams {
 	)]
 	pub execution: Option<ExecutionStrategy>,
 }
+
+/// Length in bytes of the block hashes accepted on the command line.
+const BLOCK_HASH_LEN: usize = 32;
+
+/// Parse a hex encoded 32 byte block hash, with or without `0x` prefix.
+fn parse_block_hash(hash: &str) -> Result<Vec<u8>, String> {
+	let hash = hash.strip_prefix("0x").unwrap_or(hash);
+	let hash = hex::decode(hash).map_err(|e| format!("Invalid block hash: {}", e))?;
+	if hash.len() != BLOCK_HASH_LEN {
+		return Err(format!(
+			"Invalid block hash: expected {} bytes, got {}",
+			BLOCK_HASH_LEN,
+			hash.len(),
+		))
+	}
+	Ok(hash)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_block_hash_checks_the_length() {
+		let hash = format!("0x{}", "42".repeat(32));
+		assert_eq!(parse_block_hash(&hash), Ok(vec![0x42; 32]));
+		assert_eq!(parse_block_hash(&hash[2..]), Ok(vec![0x42; 32]));
+		assert!(parse_block_hash(&hash[..hash.len() - 2]).is_err());
+		assert!(parse_block_hash(&format!("{}42", hash)).is_err());
+	}
+}
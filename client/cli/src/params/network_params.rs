@@ -53,6 +53,9 @@ pub struct NetworkParams {
 	pub public_addr: Vec<Multiaddr>,
 
 	/// Listen on this multiaddress.
+	///
+	/// Appending `/ws` to a TCP address, e.g. `/ip4/0.0.0.0/tcp/30334/ws`, accepts WebSocket
+	/// connections, for instance from browser light clients.
 	#[structopt(long = "listen-addr", value_name = "LISTEN_ADDR")]
 	pub listen_addr: Vec<Multiaddr>,
 
@@ -92,6 +95,16 @@ pub struct NetworkParams {
 	)]
 	pub max_parallel_downloads: u32,
 
+	/// Maximum number of light client requests to have in flight at the same time.
+	///
+	/// Requests exceeding this limit are queued until an in-flight request completes.
+	#[structopt(
+		long = "max-parallel-light-client-requests",
+		value_name = "COUNT",
+		default_value = "16"
+	)]
+	pub max_parallel_light_client_requests: u32,
+
 	#[allow(missing_docs)]
 	#[structopt(flatten)]
 	pub node_key_params: NodeKeyParams,
@@ -181,6 +194,7 @@ impl NetworkParams {
 				wasm_external_transport: None,
 			},
 			max_parallel_downloads: self.max_parallel_downloads,
+			max_parallel_light_client_requests: self.max_parallel_light_client_requests,
 			enable_dht_random_walk: !self.reserved_only,
 			allow_non_globals_in_dht,
 			kademlia_disjoint_query_paths: self.kademlia_disjoint_query_paths,
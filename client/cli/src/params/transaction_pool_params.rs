@@ -18,6 +18,7 @@
 
 use sc_service::config::TransactionPoolOptions;
 use structopt::StructOpt;
+use std::num::NonZeroUsize;
 
 /// Parameters used to create the pool configuration.
 #[derive(Debug, StructOpt)]
@@ -29,6 +30,10 @@ pub struct TransactionPoolParams {
 	/// Maximum number of kilobytes of all transactions stored in the pool.
 	#[structopt(long = "pool-kbytes", value_name = "COUNT", default_value = "20480")]
 	pub pool_kbytes: usize,
+
+	/// Number of threads used to validate incoming transactions, at least one.
+	#[structopt(long = "pool-validation-threads", value_name = "COUNT", default_value = "2")]
+	pub pool_validation_threads: NonZeroUsize,
 }
 
 impl TransactionPoolParams {
@@ -45,6 +50,8 @@ impl TransactionPoolParams {
 		opts.future.count = self.pool_limit / factor;
 		opts.future.total_bytes = self.pool_kbytes * 1024 / factor;
 
+		opts.validation_threads = self.pool_validation_threads.get();
+
 		opts
 	}
 }
@@ -49,7 +49,7 @@ use sp_consensus::{
 		Verifier, BasicQueue, DefaultImportQueue, BoxJustificationImport,
 	},
 };
-use sc_client_api::{backend::AuxStore, BlockOf};
+use sc_client_api::{backend::AuxStore, BlockchainEvents, BlockOf};
 use sp_blockchain::{
 	self, Result as CResult, well_known_cache_keys::{self, Id as CacheKeyId},
 	ProvideCache, HeaderBackend,
@@ -69,8 +69,8 @@ use sp_timestamp::{
 use sc_telemetry::{telemetry, CONSENSUS_TRACE, CONSENSUS_DEBUG, CONSENSUS_INFO};
 
 use sc_consensus_slots::{
-	CheckedHeader, SlotInfo, SlotCompatible, StorageChanges, check_equivocation,
-	BackoffAuthoringBlocksStrategy,
+	CheckedHeader, SlotInfo, SlotCompatible, StorageChanges, check_equivocation_with_pruning,
+	prune_on_finality, BackoffAuthoringBlocksStrategy, SlotHeaderPruning, MAX_SLOT_CAPACITY,
 };
 use sp_consensus_slots::Slot;
 
@@ -84,6 +84,7 @@ pub use sp_consensus_aura::{
 	},
 };
 pub use sp_consensus::SyncOracle;
+pub use sc_consensus_slots::SlotHeaderPruning;
 pub use digests::CompatibleDigestItem;
 
 mod digests;
@@ -137,6 +138,8 @@ impl SlotCompatible for AuraSlotCompatible {
 }
 
 /// Start the aura worker. The returned future should be run in a futures executor.
+///
+/// `slot_header_pruning` must be the mode the [`import_queue`] was started with.
 pub fn start_aura<B, C, SC, E, I, P, SO, CAW, BS, Error>(
 	slot_duration: SlotDuration,
 	client: Arc<C>,
@@ -148,10 +151,12 @@ pub fn start_aura<B, C, SC, E, I, P, SO, CAW, BS, Error>(
 	force_authoring: bool,
 	backoff_authoring_blocks: Option<BS>,
 	keystore: SyncCryptoStorePtr,
+	slot_header_pruning: SlotHeaderPruning,
 	can_author_with: CAW,
 ) -> Result<impl Future<Output = ()>, sp_consensus::Error> where
 	B: BlockT,
-	C: ProvideRuntimeApi<B> + BlockOf + ProvideCache<B> + AuxStore + HeaderBackend<B> + Send + Sync,
+	C: ProvideRuntimeApi<B> + BlockOf + ProvideCache<B> + AuxStore + HeaderBackend<B>
+		+ Send + Sync + 'static,
 	C::Api: AuraApi<B, AuthorityId<P>>,
 	SC: SelectChain<B>,
 	E: Environment<B, Error = Error> + Send + Sync + 'static,
@@ -173,6 +178,7 @@ pub fn start_aura<B, C, SC, E, I, P, SO, CAW, BS, Error>(
 		sync_oracle: sync_oracle.clone(),
 		force_authoring,
 		backoff_authoring_blocks,
+		slot_header_pruning,
 		_key_type: PhantomData::<P>,
 	};
 	register_aura_inherent_data_provider(
@@ -198,6 +204,7 @@ struct AuraWorker<C, E, I, P, SO, BS> {
 	sync_oracle: SO,
 	force_authoring: bool,
 	backoff_authoring_blocks: Option<BS>,
+	slot_header_pruning: SlotHeaderPruning,
 	_key_type: PhantomData<P>,
 }
 
@@ -205,7 +212,8 @@ impl<B, C, E, I, P, Error, SO, BS> sc_consensus_slots::SimpleSlotWorker<B>
 	for AuraWorker<C, E, I, P, SO, BS>
 where
 	B: BlockT,
-	C: ProvideRuntimeApi<B> + BlockOf + ProvideCache<B> + HeaderBackend<B> + Sync,
+	C: ProvideRuntimeApi<B> + BlockOf + ProvideCache<B> + AuxStore + HeaderBackend<B>
+		+ Send + Sync + 'static,
 	C::Api: AuraApi<B, AuthorityId<P>>,
 	E: Environment<B, Error = Error>,
 	E::Proposer: Proposer<B, Error = Error, Transaction = sp_api::TransactionFor<C, B>>,
@@ -248,7 +256,7 @@ where
 
 	fn claim_slot(
 		&self,
-		_header: &B::Header,
+		header: &B::Header,
 		slot: Slot,
 		epoch_data: &Self::EpochData,
 	) -> Option<Self::Claim> {
@@ -287,7 +295,28 @@ where
 		sp_consensus::Error> + Send + 'static>
 	{
 		let keystore = self.keystore.clone();
+		let client = self.client.clone();
+		let slot_header_pruning = self.slot_header_pruning;
 		Box::new(move |header, header_hash, body, storage_changes, public, _epoch| {
+			// record the block in the slot header map before signing it, so that we never
+			// sign a second, different block for a slot we have already authored.
+			let slot = find_pre_digest::<B, P>(&header)
+				.map_err(|e| sp_consensus::Error::ClientImport(e.to_string()))?;
+			let equivocation = check_equivocation_with_pruning(
+				&*client,
+				slot,
+				slot,
+				&header,
+				&public,
+				slot_header_pruning,
+			).map_err(|e| sp_consensus::Error::ClientImport(e.to_string()))?;
+			if equivocation.is_some() {
+				return Err(sp_consensus::Error::ClientImport(format!(
+					"Refusing to author a second block for slot {}",
+					slot,
+				)));
+			}
+
 			// sign the pre-sealed hash of the block and then
 			// add it to a digest item.
 			let public_type_pair = public.to_public_crypto_pair();
@@ -336,6 +365,10 @@ where
 		false
 	}
 
+	fn header_slot(&self, header: &B::Header) -> Option<Slot> {
+		find_pre_digest::<B, P>(header).ok()
+	}
+
 	fn sync_oracle(&mut self) -> &mut Self::SyncOracle {
 		&mut self.sync_oracle
 	}
@@ -443,6 +476,7 @@ fn check_header<C, B: BlockT, P: Pair>(
 	mut header: B::Header,
 	hash: B::Hash,
 	authorities: &[AuthorityId<P>],
+	slot_header_pruning: SlotHeaderPruning,
 ) -> Result<CheckedHeader<B::Header, (Slot, DigestItemFor<B>)>, Error<B>> where
 	DigestItemFor<B>: CompatibleDigestItem<P>,
 	P::Signature: Decode,
@@ -474,12 +508,13 @@ fn check_header<C, B: BlockT, P: Pair>(
 		let pre_hash = header.hash();
 
 		if P::verify(&sig, pre_hash.as_ref(), expected_author) {
-			if let Some(equivocation_proof) = check_equivocation(
+			if let Some(equivocation_proof) = check_equivocation_with_pruning(
 				client,
 				slot_now,
 				slot,
 				&header,
 				expected_author,
+				slot_header_pruning,
 			).map_err(Error::Client)? {
 				info!(
 					"Slot author is equivocating at slot {} with headers {:?} and {:?}",
@@ -502,6 +537,7 @@ pub struct AuraVerifier<C, P, CAW> {
 	phantom: PhantomData<P>,
 	inherent_data_providers: sp_inherents::InherentDataProviders,
 	can_author_with: CAW,
+	slot_header_pruning: SlotHeaderPruning,
 }
 
 impl<C, P, CAW> AuraVerifier<C, P, CAW> where
@@ -611,6 +647,7 @@ impl<B: BlockT, C, P, CAW> Verifier<B> for AuraVerifier<C, P, CAW> where
 			header,
 			hash,
 			&authorities[..],
+			self.slot_header_pruning,
 		).map_err(|e| e.to_string())?;
 		match checked_header {
 			CheckedHeader::Checked(pre_header, (slot, seal)) => {
@@ -831,6 +868,9 @@ impl<Block: BlockT, C, I, P> BlockImport<Block> for AuraBlockImport<Block, C, I,
 }
 
 /// Start an import queue for the Aura consensus algorithm.
+///
+/// With [`SlotHeaderPruning::Finality`], a task pruning the slot header map on finality is
+/// spawned alongside the queue.
 pub fn import_queue<B, I, C, P, S, CAW>(
 	slot_duration: SlotDuration,
 	block_import: I,
@@ -839,11 +879,13 @@ pub fn import_queue<B, I, C, P, S, CAW>(
 	inherent_data_providers: InherentDataProviders,
 	spawner: &S,
 	registry: Option<&Registry>,
+	slot_header_pruning: SlotHeaderPruning,
 	can_author_with: CAW,
 ) -> Result<DefaultImportQueue<B, C>, sp_consensus::Error> where
 	B: BlockT,
 	C::Api: BlockBuilderApi<B> + AuraApi<B, AuthorityId<P>> + ApiExt<B, Error = sp_blockchain::Error>,
 	C: 'static + ProvideRuntimeApi<B> + BlockOf + ProvideCache<B> + Send + Sync + AuxStore + HeaderBackend<B>,
+	C: BlockchainEvents<B>,
 	I: BlockImport<B, Error=ConsensusError, Transaction = sp_api::TransactionFor<C, B>> + Send + Sync + 'static,
 	DigestItemFor<B>: CompatibleDigestItem<P>,
 	P: Pair + Send + Sync + 'static,
@@ -855,11 +897,23 @@ pub fn import_queue<B, I, C, P, S, CAW>(
 	register_aura_inherent_data_provider(&inherent_data_providers, slot_duration.get())?;
 	initialize_authorities_cache(&*client)?;
 
+	if slot_header_pruning == SlotHeaderPruning::Finality {
+		spawner.spawn(
+			"aura-slot-header-pruning",
+			prune_on_finality::<B, _, _>(
+				client.clone(),
+				MAX_SLOT_CAPACITY,
+				|header| find_pre_digest::<B, P>(header).ok(),
+			).boxed(),
+		);
+	}
+
 	let verifier = AuraVerifier {
 		client,
 		inherent_data_providers,
 		phantom: PhantomData,
 		can_author_with,
+		slot_header_pruning,
 	};
 
 	Ok(BasicQueue::new(
@@ -967,6 +1021,7 @@ mod tests {
 						inherent_data_providers,
 						phantom: Default::default(),
 						can_author_with: AlwaysCanAuthor,
+						slot_header_pruning: SlotHeaderPruning::SlotDistance,
 					}
 				},
 				PeersClient::Light(_, _) => unreachable!("No (yet) tests for light client + Aura"),
@@ -1042,6 +1097,7 @@ mod tests {
 				false,
 				Some(BackoffAuthoringOnFinalizedHeadLagging::default()),
 				keystore,
+				SlotHeaderPruning::SlotDistance,
 				sp_consensus::AlwaysCanAuthor,
 			).expect("Starts aura"));
 		}
@@ -1102,6 +1158,7 @@ mod tests {
 			sync_oracle: DummyOracle.clone(),
 			force_authoring: false,
 			backoff_authoring_blocks: Some(BackoffAuthoringOnFinalizedHeadLagging::default()),
+			slot_header_pruning: SlotHeaderPruning::SlotDistance,
 			_key_type: PhantomData::<AuthorityPair>,
 		};
 
@@ -1121,4 +1178,104 @@ mod tests {
 		assert!(worker.claim_slot(&head, 6.into(), &authorities).is_none());
 		assert!(worker.claim_slot(&head, 7.into(), &authorities).is_some());
 	}
+
+	#[test]
+	fn any_local_authority_key_should_claim_slot() {
+		let mut net = AuraTestNet::new(1);
+
+		let keystore_path = tempfile::tempdir().expect("Creates keystore path");
+		let keystore = LocalKeystore::open(keystore_path.path(), None)
+			.expect("Creates keystore.");
+		let first = SyncCryptoStore::sr25519_generate_new(&keystore, AuthorityPair::ID, None)
+			.expect("Key should be created");
+		let second = SyncCryptoStore::sr25519_generate_new(&keystore, AuthorityPair::ID, None)
+			.expect("Key should be created");
+
+		let authorities = vec![
+			Keyring::Alice.public().into(),
+			first.into(),
+			Keyring::Bob.public().into(),
+			second.into(),
+		];
+
+		let peer = net.peer(0);
+		let client = peer.client().as_full().expect("full clients are created").clone();
+		let environ = DummyFactory(client.clone());
+
+		let worker = AuraWorker {
+			client: client.clone(),
+			block_import: Arc::new(Mutex::new(client)),
+			env: environ,
+			keystore: keystore.into(),
+			sync_oracle: DummyOracle.clone(),
+			force_authoring: false,
+			backoff_authoring_blocks: Option::<()>::None,
+			slot_header_pruning: SlotHeaderPruning::SlotDistance,
+			_key_type: PhantomData::<AuthorityPair>,
+		};
+
+		let head = Header::new(
+			1,
+			H256::from_low_u64_be(0),
+			H256::from_low_u64_be(0),
+			Default::default(),
+			Default::default()
+		);
+		assert_eq!(worker.claim_slot(&head, 1.into(), &authorities), Some(first.into()));
+		assert!(worker.claim_slot(&head, 2.into(), &authorities).is_none());
+		assert_eq!(worker.claim_slot(&head, 3.into(), &authorities), Some(second.into()));
+		assert!(worker.claim_slot(&head, 4.into(), &authorities).is_none());
+	}
+
+	#[test]
+	fn should_not_sign_two_blocks_for_the_same_slot() {
+		let mut net = AuraTestNet::new(1);
+
+		let keystore_path = tempfile::tempdir().expect("Creates keystore path");
+		let keystore = LocalKeystore::open(keystore_path.path(), None)
+			.expect("Creates keystore.");
+		let public: sp_consensus_aura::sr25519::AuthorityId = SyncCryptoStore::sr25519_generate_new(
+			&keystore,
+			AuthorityPair::ID,
+			None,
+		).expect("Key should be created").into();
+
+		let peer = net.peer(0);
+		let client = peer.client().as_full().expect("full clients are created").clone();
+		let environ = DummyFactory(client.clone());
+
+		let worker = AuraWorker {
+			client: client.clone(),
+			block_import: Arc::new(Mutex::new(client)),
+			env: environ,
+			keystore: keystore.into(),
+			sync_oracle: DummyOracle.clone(),
+			force_authoring: false,
+			backoff_authoring_blocks: Option::<()>::None,
+			slot_header_pruning: SlotHeaderPruning::SlotDistance,
+			_key_type: PhantomData::<AuthorityPair>,
+		};
+
+		let header_with_state_root = |state_root| Header::new(
+			1,
+			H256::from_low_u64_be(0),
+			state_root,
+			Default::default(),
+			sp_runtime::generic::Digest { logs: vec![
+				<DigestItemFor<TestBlock> as CompatibleDigestItem<AuthorityPair>>::aura_pre_digest(1.into()),
+			] },
+		);
+
+		let block_import_params = worker.block_import_params();
+		let sign = |header: Header| {
+			let hash = header.hash();
+			block_import_params(header, &hash, Vec::new(), Default::default(), public.clone(), Vec::new())
+		};
+
+		let first = header_with_state_root(H256::from_low_u64_be(1));
+		assert!(sign(first.clone()).is_ok());
+		// signing the very same block again is not an equivocation.
+		assert!(sign(first).is_ok());
+		assert!(sign(header_with_state_root(H256::from_low_u64_be(2))).is_err());
+	}
 }
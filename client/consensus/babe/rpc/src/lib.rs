@@ -51,6 +51,8 @@ type FutureResult<T> = Box<dyn rpc_future::Future<Item = T, Error = RpcError> +
 pub trait BabeApi {
 	/// Returns data about which slots (primary or secondary) can be claimed in the current epoch
 	/// with the keys in the keystore.
+	///
+	/// Keys that are not part of the current epoch's authorities are not reported.
 	#[rpc(name = "babe_epochAuthorship")]
 	fn epoch_authorship(&self) -> FutureResult<HashMap<AuthorityId, EpochAuthorship>>;
 }
@@ -148,6 +150,12 @@ impl<B, C, SC> BabeApi for BabeRpcHandler<B, C, SC>
 					.collect::<Vec<_>>()
 			};
 
+			// Report every local key that is part of the epoch's authorities, even if it can't
+			// claim any slot, so that a missing key points to a keystore misconfiguration.
+			for (key, _) in &keys {
+				claims.entry(key.clone()).or_default();
+			}
+
 			for slot in *epoch_start..*epoch_end {
 				if let Some((claim, key)) =
 					authorship::claim_slot_using_keys(slot.into(), &epoch, &keystore, &keys)
@@ -241,12 +249,17 @@ mod tests {
 	};
 	use sp_application_crypto::AppPair;
 	use sp_keyring::Sr25519Keyring;
-	use sp_core::{crypto::key_types::BABE};
+	use sp_core::{Encode, crypto::key_types::BABE};
 	use sp_keystore::{SyncCryptoStorePtr, SyncCryptoStore};
+	use sp_consensus::SlotData;
+	use sp_consensus_babe::AllowedSlots;
+	use sc_client_api::AuxStore;
 	use sc_keystore::LocalKeystore;
 
 	use std::sync::Arc;
-	use sc_consensus_babe::{Config, block_import, AuthorityPair};
+	use sc_consensus_babe::{
+		Config, block_import, AuthorityPair, BabeGenesisConfiguration, SlotHeaderPruning,
+	};
 	use jsonrpc_core::IoHandler;
 
 	/// creates keystore backed by a temp file
@@ -264,19 +277,45 @@ mod tests {
 
 	fn test_babe_rpc_handler(
 		deny_unsafe: DenyUnsafe
+	) -> BabeRpcHandler<Block, TestClient, sc_consensus::LongestChain<Backend, Block>> {
+		test_babe_rpc_handler_with_authority(deny_unsafe, Sr25519Keyring::Alice)
+	}
+
+	fn test_babe_rpc_handler_with_authority(
+		deny_unsafe: DenyUnsafe,
+		authority: Sr25519Keyring,
+	) -> BabeRpcHandler<Block, TestClient, sc_consensus::LongestChain<Backend, Block>> {
+		test_babe_rpc_handler_with_config(deny_unsafe, authority, |_| {})
+	}
+
+	fn test_babe_rpc_handler_with_config(
+		deny_unsafe: DenyUnsafe,
+		authority: Sr25519Keyring,
+		configure: impl FnOnce(&mut BabeGenesisConfiguration),
 	) -> BabeRpcHandler<Block, TestClient, sc_consensus::LongestChain<Backend, Block>> {
 		let builder = TestClientBuilder::new();
 		let (client, longest_chain) = builder.build_with_longest_chain();
 		let client = Arc::new(client);
+
+		// the configuration is kept in the aux store once computed, which allows overriding the
+		// one of the test runtime.
+		let mut genesis_config: BabeGenesisConfiguration =
+			(*Config::get_or_compute(&*client).expect("config available")).clone();
+		configure(&mut genesis_config);
+		client.insert_aux(
+			&[(BabeGenesisConfiguration::SLOT_KEY, &genesis_config.encode()[..])],
+			&[],
+		).expect("inserts babe configuration");
 		let config = Config::get_or_compute(&*client).expect("config available");
 		let (_, link) = block_import(
 			config.clone(),
 			client.clone(),
 			client.clone(),
+			SlotHeaderPruning::SlotDistance,
 		).expect("can initialize block-import");
 
 		let epoch_changes = link.epoch_changes().clone();
-		let keystore = create_temp_keystore::<AuthorityPair>(Sr25519Keyring::Alice).0;
+		let keystore = create_temp_keystore::<AuthorityPair>(authority).0;
 
 		BabeRpcHandler::new(
 			client.clone(),
@@ -300,6 +339,38 @@ mod tests {
 		assert_eq!(Some(response.into()), io.handle_request_sync(request));
 	}
 
+	#[test]
+	fn epoch_authorship_ignores_keys_outside_of_authorities() {
+		let handler = test_babe_rpc_handler_with_authority(DenyUnsafe::No, Sr25519Keyring::Ferdie);
+		let mut io = IoHandler::new();
+
+		io.extend_with(BabeApi::to_delegate(handler));
+		let request = r#"{"jsonrpc":"2.0","method":"babe_epochAuthorship","params": [],"id":1}"#;
+		let response = r#"{"jsonrpc":"2.0","result":{},"id":1}"#;
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
+	#[test]
+	fn epoch_authorship_reports_authorities_without_claimable_slots() {
+		// with only primary slots and a zero `c` no slot can ever be claimed.
+		let handler = test_babe_rpc_handler_with_config(
+			DenyUnsafe::No,
+			Sr25519Keyring::Alice,
+			|config| {
+				config.c = (0, 4);
+				config.allowed_slots = AllowedSlots::PrimarySlots;
+			},
+		);
+		let mut io = IoHandler::new();
+
+		io.extend_with(BabeApi::to_delegate(handler));
+		let request = r#"{"jsonrpc":"2.0","method":"babe_epochAuthorship","params": [],"id":1}"#;
+		let response = r#"{"jsonrpc":"2.0","result":{"5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY":{"primary":[],"secondary":[],"secondary_vrf":[]}},"id":1}"#;
+
+		assert_eq!(Some(response.into()), io.handle_request_sync(request));
+	}
+
 	#[test]
 	fn epoch_authorship_is_unsafe() {
 		let handler = test_babe_rpc_handler(DenyUnsafe::Yes);
@@ -74,6 +74,7 @@ pub use sp_consensus_babe::{
 	},
 };
 pub use sp_consensus::SyncOracle;
+pub use sc_consensus_slots::SlotHeaderPruning;
 use std::{
 	collections::HashMap, sync::Arc, u64, pin::Pin, time::{Instant, Duration},
 	any::Any, borrow::Cow, convert::TryInto,
@@ -108,8 +109,8 @@ use futures::prelude::*;
 use log::{debug, info, log, trace, warn};
 use prometheus_endpoint::Registry;
 use sc_consensus_slots::{
-	SlotInfo, SlotCompatible, StorageChanges, CheckedHeader, check_equivocation,
-	BackoffAuthoringBlocksStrategy
+	SlotInfo, SlotCompatible, StorageChanges, CheckedHeader, check_equivocation_with_pruning,
+	prune_on_finality, BackoffAuthoringBlocksStrategy, MAX_SLOT_CAPACITY,
 };
 use sc_consensus_epochs::{
 	descendent_query, SharedEpochChanges, EpochChangesFor, Epoch as EpochT, ViableEpochDescriptor,
@@ -412,7 +413,8 @@ pub fn start_babe<B, C, SC, E, I, SO, CAW, BS, Error>(BabeParams {
 > where
 	B: BlockT,
 	C: ProvideRuntimeApi<B> + ProvideCache<B> + ProvideUncles<B> + BlockchainEvents<B>
-		+ HeaderBackend<B> + HeaderMetadata<B, Error = ClientError> + Send + Sync + 'static,
+		+ AuxStore + HeaderBackend<B> + HeaderMetadata<B, Error = ClientError> + Send + Sync
+		+ 'static,
 	C::Api: BabeApi<B>,
 	SC: SelectChain<B> + 'static,
 	E: Environment<B, Error = Error> + Send + Sync + 'static,
@@ -438,6 +440,7 @@ pub fn start_babe<B, C, SC, E, I, SO, CAW, BS, Error>(BabeParams {
 		epoch_changes: babe_link.epoch_changes.clone(),
 		slot_notification_sinks: slot_notification_sinks.clone(),
 		config: config.clone(),
+		slot_header_pruning: babe_link.slot_header_pruning,
 	};
 
 	register_babe_inherent_data_provider(&inherent_data_providers, config.slot_duration())?;
@@ -511,6 +514,7 @@ struct BabeSlotWorker<B: BlockT, C, E, I, SO, BS> {
 	epoch_changes: SharedEpochChanges<B, Epoch>,
 	slot_notification_sinks: SlotNotificationSinks<B>,
 	config: Config,
+	slot_header_pruning: SlotHeaderPruning,
 }
 
 impl<B, C, E, I, Error, SO, BS> sc_consensus_slots::SimpleSlotWorker<B>
@@ -519,8 +523,12 @@ where
 	B: BlockT,
 	C: ProvideRuntimeApi<B> +
 		ProvideCache<B> +
+		AuxStore +
 		HeaderBackend<B> +
-		HeaderMetadata<B, Error = ClientError>,
+		HeaderMetadata<B, Error = ClientError> +
+		Send +
+		Sync +
+		'static,
 	C::Api: BabeApi<B>,
 	E: Environment<B, Error = Error>,
 	E::Proposer: Proposer<B, Error = Error, Transaction = sp_api::TransactionFor<C, B>>,
@@ -634,7 +642,34 @@ where
 		sp_consensus::Error> + Send + 'static>
 	{
 		let keystore = self.keystore.clone();
-		Box::new(move |header, header_hash, body, storage_changes, (_, public), epoch_descriptor| {
+		let client = self.client.clone();
+		let slot_header_pruning = self.slot_header_pruning;
+		Box::new(move |
+			header,
+			header_hash,
+			body,
+			storage_changes,
+			(pre_digest, public),
+			epoch_descriptor,
+		| {
+			// record the block in the slot header map before signing it, so that we never
+			// sign a second, different block for a slot we have already authored.
+			let slot = pre_digest.slot();
+			let equivocation = check_equivocation_with_pruning(
+				&*client,
+				slot,
+				slot,
+				&header,
+				&public,
+				slot_header_pruning,
+			).map_err(|e| sp_consensus::Error::ClientImport(e.to_string()))?;
+			if equivocation.is_some() {
+				return Err(sp_consensus::Error::ClientImport(format!(
+					"Refusing to author a second block for slot {}",
+					slot,
+				)));
+			}
+
 			// sign the pre-sealed hash of the block and then
 			// add it to a digest item.
 			let public_type_pair = public.clone().into();
@@ -688,6 +723,10 @@ where
 		false
 	}
 
+	fn header_slot(&self, header: &B::Header) -> Option<Slot> {
+		find_pre_digest::<B>(header).ok().map(|digest| digest.slot())
+	}
+
 	fn sync_oracle(&mut self) -> &mut Self::SyncOracle {
 		&mut self.sync_oracle
 	}
@@ -817,6 +856,7 @@ pub struct BabeLink<Block: BlockT> {
 	time_source: TimeSource,
 	epoch_changes: SharedEpochChanges<Block, Epoch>,
 	config: Config,
+	slot_header_pruning: SlotHeaderPruning,
 }
 
 impl<Block: BlockT> BabeLink<Block> {
@@ -840,6 +880,7 @@ pub struct BabeVerifier<Block: BlockT, Client, SelectChain, CAW> {
 	epoch_changes: SharedEpochChanges<Block, Epoch>,
 	time_source: TimeSource,
 	can_author_with: CAW,
+	slot_header_pruning: SlotHeaderPruning,
 }
 
 impl<Block, Client, SelectChain, CAW> BabeVerifier<Block, Client, SelectChain, CAW>
@@ -899,10 +940,14 @@ where
 		}
 
 		// check if authorship of this header is an equivocation and return a proof if so.
-		let equivocation_proof =
-			match check_equivocation(&*self.client, slot_now, slot, header, author)
-				.map_err(Error::Client)?
-			{
+		let equivocation_proof = match check_equivocation_with_pruning(
+			&*self.client,
+			slot_now,
+			slot,
+			header,
+			author,
+			self.slot_header_pruning,
+		).map_err(Error::Client)? {
 				Some(proof) => proof,
 				None => return Ok(()),
 			};
@@ -1441,11 +1486,13 @@ fn prune_finalized<Block, Client>(
 /// an import-queue.
 ///
 /// Also returns a link object used to correctly instantiate the import queue
-/// and background worker.
+/// and background worker, both of which maintain the slot header map with
+/// `slot_header_pruning`.
 pub fn block_import<Client, Block: BlockT, I>(
 	config: Config,
 	wrapped_block_import: I,
 	client: Arc<Client>,
+	slot_header_pruning: SlotHeaderPruning,
 ) -> ClientResult<(BabeBlockImport<Block, Client, I>, BabeLink<Block>)> where
 	Client: AuxStore + HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error>,
 {
@@ -1454,6 +1501,7 @@ pub fn block_import<Client, Block: BlockT, I>(
 		epoch_changes: epoch_changes.clone(),
 		time_source: Default::default(),
 		config: config.clone(),
+		slot_header_pruning,
 	};
 
 	// NOTE: this isn't entirely necessary, but since we didn't use to prune the
@@ -1483,6 +1531,9 @@ pub fn block_import<Client, Block: BlockT, I>(
 ///
 /// The block import object provided must be the `BabeBlockImport` or a wrapper
 /// of it, otherwise crucial import logic will be omitted.
+///
+/// With [`SlotHeaderPruning::Finality`], a task pruning the slot header map on
+/// finality is spawned alongside the queue.
 pub fn import_queue<Block: BlockT, Client, SelectChain, Inner, CAW>(
 	babe_link: BabeLink<Block>,
 	block_import: Inner,
@@ -1498,12 +1549,24 @@ pub fn import_queue<Block: BlockT, Client, SelectChain, Inner, CAW>(
 		+ Send + Sync + 'static,
 	Client: ProvideRuntimeApi<Block> + ProvideCache<Block> + Send + Sync + AuxStore + 'static,
 	Client: HeaderBackend<Block> + HeaderMetadata<Block, Error = sp_blockchain::Error>,
+	Client: BlockchainEvents<Block>,
 	Client::Api: BlockBuilderApi<Block> + BabeApi<Block> + ApiExt<Block, Error = sp_blockchain::Error>,
 	SelectChain: sp_consensus::SelectChain<Block> + 'static,
 	CAW: CanAuthorWith<Block> + Send + Sync + 'static,
 {
 	register_babe_inherent_data_provider(&inherent_data_providers, babe_link.config.slot_duration)?;
 
+	if babe_link.slot_header_pruning == SlotHeaderPruning::Finality {
+		spawner.spawn(
+			"babe-slot-header-pruning",
+			prune_on_finality::<Block, _, _>(
+				client.clone(),
+				MAX_SLOT_CAPACITY,
+				|header| find_pre_digest::<Block>(header).ok().map(|digest| digest.slot()),
+			).boxed(),
+		);
+	}
+
 	let verifier = BabeVerifier {
 		client,
 		select_chain,
@@ -1512,6 +1575,7 @@ pub fn import_queue<Block: BlockT, Client, SelectChain, Inner, CAW>(
 		epoch_changes: babe_link.epoch_changes,
 		time_source: babe_link.time_source,
 		can_author_with,
+		slot_header_pruning: babe_link.slot_header_pruning,
 	};
 
 	Ok(BasicQueue::new(
@@ -29,7 +29,7 @@ use sp_keystore::{
 	vrf::make_transcript as transcript_from_data,
 };
 use sp_consensus_babe::{AuthorityPair, Slot, AllowedSlots, make_transcript, make_transcript_data};
-use sc_consensus_slots::BackoffAuthoringOnFinalizedHeadLagging;
+use sc_consensus_slots::{BackoffAuthoringOnFinalizedHeadLagging, SimpleSlotWorker};
 use sc_block_builder::{BlockBuilder, BlockBuilderProvider};
 use sp_consensus::{
 	NoNetwork as DummyOracle, Proposal, RecordProof, AlwaysCanAuthor,
@@ -279,6 +279,7 @@ impl TestNetFactory for BabeTestNet {
 			config,
 			client.clone(),
 			client.clone(),
+			SlotHeaderPruning::SlotDistance,
 		).expect("can initialize block-import");
 
 		let block_import = PanickingBlockImport(block_import);
@@ -320,6 +321,7 @@ impl TestNetFactory for BabeTestNet {
 				epoch_changes: data.link.epoch_changes.clone(),
 				time_source: data.link.time_source.clone(),
 				can_author_with: AlwaysCanAuthor,
+				slot_header_pruning: data.link.slot_header_pruning,
 			},
 			mutator: MUTATOR.with(|m| m.borrow().clone()),
 		}
@@ -820,6 +822,75 @@ fn verify_slots_are_strictly_increasing() {
 	);
 }
 
+#[test]
+fn should_not_sign_two_blocks_for_the_same_slot() {
+	let mut net = BabeTestNet::new(1);
+
+	let keystore_path = tempfile::tempdir().expect("Creates keystore path");
+	let keystore: SyncCryptoStorePtr = Arc::new(LocalKeystore::open(keystore_path.path(), None)
+		.expect("Creates keystore"));
+	let public: AuthorityId =
+		SyncCryptoStore::sr25519_generate_new(&*keystore, BABE, Some("//Alice"))
+			.expect("Generates authority pair")
+			.into();
+
+	let peer = net.peer(0);
+	let data = peer.data.as_ref().expect("babe link set up during initialization");
+	let client = peer.client().as_full().expect("Only full clients are used in tests").clone();
+	let block_import = data.block_import.lock().take().expect("import set up during init");
+
+	let worker = BabeSlotWorker {
+		client: client.clone(),
+		block_import: Arc::new(Mutex::new(block_import)),
+		env: DummyFactory {
+			client: client.clone(),
+			config: data.link.config.clone(),
+			epoch_changes: data.link.epoch_changes.clone(),
+			mutator: Arc::new(|_, _| ()),
+		},
+		sync_oracle: DummyOracle,
+		force_authoring: false,
+		backoff_authoring_blocks: Option::<()>::None,
+		keystore,
+		epoch_changes: data.link.epoch_changes.clone(),
+		slot_notification_sinks: Arc::new(Mutex::new(Vec::new())),
+		config: data.link.config.clone(),
+		slot_header_pruning: SlotHeaderPruning::SlotDistance,
+	};
+
+	let genesis_hash = client.chain_info().genesis_hash;
+	let pre_digest = PreDigest::SecondaryPlain(SecondaryPlainPreDigest {
+		authority_index: 0,
+		slot: 1.into(),
+	});
+	let header_with_state_root = |state_root| TestHeader::new(
+		1,
+		Default::default(),
+		state_root,
+		genesis_hash,
+		sp_runtime::generic::Digest { logs: vec![Item::babe_pre_digest(pre_digest.clone())] },
+	);
+
+	let block_import_params = worker.block_import_params();
+	let sign = |header: TestHeader| {
+		let hash = header.hash();
+		block_import_params(
+			header,
+			&hash,
+			Vec::new(),
+			Default::default(),
+			(pre_digest.clone(), public.clone()),
+			ViableEpochDescriptor::UnimportedGenesis(1.into()),
+		)
+	};
+
+	let first = header_with_state_root(sp_core::H256::from_low_u64_be(1));
+	assert!(sign(first.clone()).is_ok());
+	// signing the very same block again is not an equivocation.
+	assert!(sign(first).is_ok());
+	assert!(sign(header_with_state_root(sp_core::H256::from_low_u64_be(2))).is_err());
+}
+
 #[test]
 fn babe_transcript_generation_match() {
 	sp_tracing::try_init_simple();
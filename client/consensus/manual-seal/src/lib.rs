@@ -30,7 +30,7 @@ use sp_inherents::InherentDataProviders;
 use sp_runtime::{traits::Block as BlockT, Justification};
 use sc_client_api::backend::{Backend as ClientBackend, Finalizer};
 use sc_transaction_pool::txpool;
-use std::{sync::Arc, marker::PhantomData};
+use std::{sync::Arc, marker::PhantomData, time::Duration};
 use prometheus_endpoint::Registry;
 
 mod error;
@@ -139,6 +139,11 @@ pub struct InstantSealParams<B: BlockT, BI, E, C: ProvideRuntimeApi<B>, A: txpoo
 
 	/// Provider for inherents to include in blocks.
 	pub inherent_data_providers: InherentDataProviders,
+
+	/// Artificial delay to wait for after a transaction is imported, before sealing a block.
+	///
+	/// Transactions imported while waiting are sealed in the same block.
+	pub delay: Option<Duration>,
 }
 
 /// Creates the background authorship task for the manual seal engine.
@@ -209,7 +214,8 @@ pub async fn run_manual_seal<B, BI, CB, E, C, A, SC, CS>(
 
 /// runs the background authorship task for the instant seal engine.
 /// instant-seal creates a new block for every transaction imported into
-/// the transaction pool.
+/// the transaction pool, optionally waiting for `delay` first, in which case
+/// all transactions imported in the meantime end up in the same block.
 pub async fn run_instant_seal<B, BI, CB, E, C, A, SC>(
 	InstantSealParams {
 		block_import,
@@ -219,7 +225,7 @@ pub async fn run_instant_seal<B, BI, CB, E, C, A, SC>(
 		select_chain,
 		consensus_data_provider,
 		inherent_data_providers,
-		..
+		delay,
 	}: InstantSealParams<B, BI, E, C, A, SC>
 )
 	where
@@ -236,16 +242,19 @@ pub async fn run_instant_seal<B, BI, CB, E, C, A, SC>(
 {
 	// instant-seal creates blocks as soon as transactions are imported
 	// into the transaction pool.
-	let commands_stream = pool.validated_pool()
-		.import_notification_stream()
-		.map(|_| {
-			EngineCommand::SealNewBlock {
-				create_empty: false,
-				finalize: false,
-				parent_hash: None,
-				sender: None,
-			}
-		});
+	let import_notifications = pool.validated_pool().import_notification_stream();
+	let import_notifications = match delay {
+		Some(delay) => debounce(import_notifications, delay).boxed(),
+		None => import_notifications.map(|_| ()).boxed(),
+	};
+	let commands_stream = import_notifications.map(|_| {
+		EngineCommand::SealNewBlock {
+			create_empty: false,
+			finalize: false,
+			parent_hash: None,
+			sender: None,
+		}
+	});
 
 	run_manual_seal(
 		ManualSealParams {
@@ -261,6 +270,30 @@ pub async fn run_instant_seal<B, BI, CB, E, C, A, SC>(
 	).await
 }
 
+/// Yields once for every burst of items from `stream`: the first item of a burst starts a timer
+/// of `delay`, and all items received until it fires are merged into that burst.
+fn debounce<S>(stream: S, delay: Duration) -> impl Stream<Item = ()>
+	where S: Stream + Unpin,
+{
+	futures::stream::unfold(stream.fuse(), move |mut stream| async move {
+		stream.next().await?;
+
+		let mut timer = futures_timer::Delay::new(delay);
+		loop {
+			match future::select(&mut timer, stream.next()).await {
+				future::Either::Left(_) => break,
+				future::Either::Right((Some(_), _)) => continue,
+				future::Either::Right((None, timer)) => {
+					timer.await;
+					break
+				},
+			}
+		}
+
+		Some(((), stream))
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -277,7 +310,7 @@ mod tests {
 	use sp_consensus::ImportedAux;
 	use sp_inherents::InherentDataProviders;
 	use sc_basic_authorship::ProposerFactory;
-	use sc_client_api::BlockBackend;
+	use sc_client_api::{BlockBackend, BlockchainEvents};
 
 	fn api() -> Arc<TestApi> {
 		Arc::new(TestApi::empty())
@@ -356,6 +389,58 @@ mod tests {
 		assert!(client.header(&BlockId::Number(1)).unwrap().is_some())
 	}
 
+	#[tokio::test]
+	async fn instant_seal_delay_merges_transactions_into_one_block() {
+		let builder = TestClientBuilder::new();
+		let (client, select_chain) = builder.build_with_longest_chain();
+		let client = Arc::new(client);
+		let inherent_data_providers = InherentDataProviders::new();
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool = Arc::new(BasicPool::with_revalidation_type(
+			Options::default(), true.into(), api(), None, RevalidationType::Full, spawner.clone(),
+		));
+		let env = ProposerFactory::new(
+			spawner.clone(),
+			client.clone(),
+			pool.clone(),
+			None,
+		);
+		let future = run_instant_seal(
+			InstantSealParams {
+				block_import: client.clone(),
+				env,
+				client: client.clone(),
+				pool: pool.pool().clone(),
+				select_chain,
+				inherent_data_providers,
+				consensus_data_provider: None,
+				delay: Some(Duration::from_millis(500)),
+			}
+		);
+		std::thread::spawn(|| {
+			let mut rt = tokio::runtime::Runtime::new().unwrap();
+			// spawn the background authorship task
+			rt.block_on(future);
+		});
+		let mut import_notifications = client.import_notification_stream();
+
+		// submit several transactions while the delay runs.
+		for who in vec![Alice, Bob, Charlie] {
+			let result = pool.submit_one(&BlockId::Number(0), SOURCE, uxt(who, 0)).await;
+			assert!(result.is_ok());
+		}
+
+		// assert that a single block contains all of them.
+		let imported = import_notifications.next().await.unwrap();
+		assert_eq!(imported.header.number, 1);
+		let body = client.block_body(&BlockId::Number(1)).unwrap().unwrap();
+		assert_eq!(body.len(), 3);
+
+		// assert that no further block is sealed for the merged notifications.
+		futures_timer::Delay::new(Duration::from_secs(1)).await;
+		assert!(client.header(&BlockId::Number(2)).unwrap().is_none());
+	}
+
 	#[tokio::test]
 	async fn manual_seal_and_finalization() {
 		let builder = TestClientBuilder::new();
@@ -70,6 +70,11 @@ pub enum EngineCommand<Hash> {
 #[rpc]
 pub trait ManualSealApi<Hash> {
 	/// Instructs the manual-seal authorship task to create a new block
+	///
+	/// If `create_empty` is false and the transaction pool is empty, no block is created and
+	/// `EmptyTransactionPool` is returned. If `finalize` is true, the block is finalized as part
+	/// of its import. The block is built on top of `parent_hash` if given, otherwise on top of
+	/// the current best block.
 	#[rpc(name = "engine_createBlock")]
 	fn create_block(
 		&self,
@@ -79,6 +84,9 @@ pub trait ManualSealApi<Hash> {
 	) -> FutureResult<CreatedBlock<Hash>>;
 
 	/// Instructs the manual-seal authorship task to finalize a block
+	///
+	/// Finalizes the block with the given `hash`, optionally along with a `justification`.
+	/// Returns `true` once the block has been finalized.
 	#[rpc(name = "engine_finalizeBlock")]
 	fn finalize_block(
 		&self,
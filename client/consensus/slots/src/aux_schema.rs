@@ -18,11 +18,15 @@
 
 //! Schema for slots in the aux-db.
 
+use std::sync::Arc;
+
 use codec::{Encode, Decode};
-use sc_client_api::backend::AuxStore;
+use futures::StreamExt;
+use log::warn;
+use sc_client_api::{backend::AuxStore, BlockchainEvents};
 use sp_blockchain::{Result as ClientResult, Error as ClientError};
 use sp_consensus_slots::{EquivocationProof, Slot};
-use sp_runtime::traits::Header;
+use sp_runtime::traits::{Block as BlockT, Header};
 
 const SLOT_HEADER_MAP_KEY: &[u8] = b"slot_header_map";
 const SLOT_HEADER_START: &[u8] = b"slot_header_start";
@@ -47,6 +51,15 @@ fn load_decode<C, T>(backend: &C, key: &[u8]) -> ClientResult<Option<T>>
 	}
 }
 
+/// How the slot header map maintained by [`check_equivocation_with_pruning`] is pruned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotHeaderPruning {
+	/// Keep at least [`MAX_SLOT_CAPACITY`] slots, pruning once [`PRUNING_BOUND`] slots are stored.
+	SlotDistance,
+	/// Keep all slots until [`prune_finalized`] is called for a newly finalized slot.
+	Finality,
+}
+
 /// Checks if the header is an equivocation and returns the proof in that case.
 ///
 /// Note: it detects equivocations only when slot_now - slot <= MAX_SLOT_CAPACITY.
@@ -61,9 +74,39 @@ pub fn check_equivocation<C, H, P>(
 		H: Header,
 		C: AuxStore,
 		P: Clone + Encode + Decode + PartialEq,
+{
+	check_equivocation_with_pruning(
+		backend,
+		slot_now,
+		slot,
+		header,
+		signer,
+		SlotHeaderPruning::SlotDistance,
+	)
+}
+
+/// Checks if the header is an equivocation and returns the proof in that case, pruning the
+/// slot header map according to `pruning`.
+///
+/// With [`SlotHeaderPruning::Finality`] equivocations are detected for all slots that weren't
+/// pruned by [`prune_finalized`] yet.
+pub fn check_equivocation_with_pruning<C, H, P>(
+	backend: &C,
+	slot_now: Slot,
+	slot: Slot,
+	header: &H,
+	signer: &P,
+	pruning: SlotHeaderPruning,
+) -> ClientResult<Option<EquivocationProof<H, P>>>
+	where
+		H: Header,
+		C: AuxStore,
+		P: Clone + Encode + Decode + PartialEq,
 {
 	// We don't check equivocations for old headers out of our capacity.
-	if slot_now.saturating_sub(*slot) > Slot::from(MAX_SLOT_CAPACITY) {
+	if pruning == SlotHeaderPruning::SlotDistance &&
+		slot_now.saturating_sub(*slot) > Slot::from(MAX_SLOT_CAPACITY)
+	{
 		return Ok(None);
 	}
 
@@ -85,6 +128,11 @@ pub fn check_equivocation<C, H, P>(
 		return Ok(None);
 	}
 
+	if pruning == SlotHeaderPruning::Finality && slot < first_saved_slot {
+		// This slot was already pruned on finality.
+		return Ok(None);
+	}
+
 	for (prev_header, prev_signer) in headers_with_sig.iter() {
 		// A proof of equivocation consists of two headers:
 		// 1) signed by the same voter,
@@ -109,7 +157,9 @@ pub fn check_equivocation<C, H, P>(
 	let mut keys_to_delete = vec![];
 	let mut new_first_saved_slot = first_saved_slot;
 
-	if *slot_now - *first_saved_slot >= PRUNING_BOUND {
+	if pruning == SlotHeaderPruning::SlotDistance &&
+		*slot_now - *first_saved_slot >= PRUNING_BOUND
+	{
 		let prefix = SLOT_HEADER_MAP_KEY.to_vec();
 		new_first_saved_slot = slot_now.saturating_sub(MAX_SLOT_CAPACITY);
 
@@ -133,6 +183,68 @@ pub fn check_equivocation<C, H, P>(
 	Ok(None)
 }
 
+/// Prunes the slot header map of all slots before `finalized_slot - margin`.
+///
+/// To be called on finality when the map is maintained with [`SlotHeaderPruning::Finality`].
+pub fn prune_finalized<C: AuxStore>(
+	backend: &C,
+	finalized_slot: Slot,
+	margin: u64,
+) -> ClientResult<()> {
+	let slot_header_start = SLOT_HEADER_START.to_vec();
+	let first_saved_slot = match load_decode::<_, Slot>(backend, &slot_header_start[..])? {
+		Some(first_saved_slot) => first_saved_slot,
+		None => return Ok(()),
+	};
+
+	let new_first_saved_slot = finalized_slot.saturating_sub(margin);
+	if new_first_saved_slot <= first_saved_slot {
+		return Ok(());
+	}
+
+	let keys_to_delete = (u64::from(first_saved_slot)..new_first_saved_slot.into())
+		.map(|s| {
+			let mut key = SLOT_HEADER_MAP_KEY.to_vec();
+			s.using_encoded(|s| key.extend(s));
+			key
+		})
+		.collect::<Vec<_>>();
+
+	backend.insert_aux(
+		&[(&slot_header_start[..], new_first_saved_slot.encode().as_slice())],
+		&keys_to_delete.iter().map(|k| &k[..]).collect::<Vec<&[u8]>>()[..],
+	)
+}
+
+/// Prunes the slot header map whenever a block is finalized, slots within `margin` of the
+/// finalized slot are kept.
+///
+/// To be run for as long as the map is maintained with [`SlotHeaderPruning::Finality`].
+/// `find_slot` extracts the slot of a finalized header.
+pub async fn prune_on_finality<B, C, F>(client: Arc<C>, margin: u64, find_slot: F)
+	where
+		B: BlockT,
+		C: BlockchainEvents<B> + AuxStore,
+		F: Fn(&B::Header) -> Option<Slot>,
+{
+	let mut finality_notifications = client.finality_notification_stream();
+	while let Some(notification) = finality_notifications.next().await {
+		let slot = match find_slot(&notification.header) {
+			Some(slot) => slot,
+			None => continue,
+		};
+
+		if let Err(e) = prune_finalized(&*client, slot, margin) {
+			warn!(
+				target: "slots",
+				"Failed to prune slot header map on finality of {:?}: {:?}",
+				notification.hash,
+				e,
+			);
+		}
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use sp_core::{sr25519, Pair};
@@ -140,7 +252,17 @@ mod test {
 	use sp_runtime::testing::{Header as HeaderTest, Digest as DigestTest};
 	use substrate_test_runtime_client;
 
-	use super::{MAX_SLOT_CAPACITY, PRUNING_BOUND, check_equivocation};
+	use std::sync::Arc;
+	use futures::FutureExt;
+	use sc_block_builder::BlockBuilderProvider;
+	use sp_consensus::BlockOrigin;
+	use sp_runtime::{generic::BlockId, traits::Block as _};
+	use substrate_test_runtime_client::{ClientBlockImportExt, ClientExt};
+
+	use super::{
+		MAX_SLOT_CAPACITY, PRUNING_BOUND, SlotHeaderPruning, check_equivocation,
+		check_equivocation_with_pruning, prune_finalized, prune_on_finality,
+	};
 
 	fn create_header(number: u64) -> HeaderTest {
 		// so that different headers for the same number get different hashes
@@ -246,4 +368,98 @@ mod test {
 			).unwrap().is_none(),
 		);
 	}
+
+	#[test]
+	fn finality_pruning_keeps_headers_until_finalized() {
+		let client = substrate_test_runtime_client::new();
+		let (pair, _seed) = sr25519::Pair::generate();
+		let public = pair.public();
+
+		let header1 = create_header(1); // @ slot 2
+		let header2 = create_header(1); // @ slot 2
+
+		assert!(
+			check_equivocation_with_pruning(
+				&client,
+				2.into(),
+				2.into(),
+				&header1,
+				&public,
+				SlotHeaderPruning::Finality,
+			).unwrap().is_none(),
+		);
+
+		// Long after slot-distance pruning would have dropped slot 2.
+		let slot_now = (PRUNING_BOUND * 2).into();
+		assert!(
+			check_equivocation_with_pruning(
+				&client,
+				slot_now,
+				2.into(),
+				&header2,
+				&public,
+				SlotHeaderPruning::Finality,
+			).unwrap().is_some(),
+		);
+
+		// Finalizing within the margin keeps the slot.
+		prune_finalized(&client, 10.into(), 8).unwrap();
+		assert!(
+			check_equivocation_with_pruning(
+				&client,
+				slot_now,
+				2.into(),
+				&header2,
+				&public,
+				SlotHeaderPruning::Finality,
+			).unwrap().is_some(),
+		);
+
+		// Once finalized past the margin, the slot is pruned.
+		prune_finalized(&client, 11.into(), 8).unwrap();
+		assert!(
+			check_equivocation_with_pruning(
+				&client,
+				slot_now,
+				2.into(),
+				&header2,
+				&public,
+				SlotHeaderPruning::Finality,
+			).unwrap().is_none(),
+		);
+	}
+
+	#[test]
+	fn prune_on_finality_prunes_slots_of_finalized_blocks() {
+		let mut client = Arc::new(substrate_test_runtime_client::new());
+		let (pair, _seed) = sr25519::Pair::generate();
+		let public = pair.public();
+
+		let header1 = create_header(1); // @ slot 2
+		let header2 = create_header(1); // @ slot 2
+		let checked_client = client.clone();
+		let check = move |header: &HeaderTest| check_equivocation_with_pruning(
+			&*checked_client,
+			(PRUNING_BOUND * 2).into(),
+			2.into(),
+			header,
+			&public,
+			SlotHeaderPruning::Finality,
+		).unwrap();
+
+		assert!(check(&header1).is_none());
+		assert!(check(&header2).is_some());
+
+		// Test runtime blocks carry no slot, consider any finalized block to be at slot 20.
+		let mut pruning = Box::pin(prune_on_finality(client.clone(), 8, |_| Some(20.into())));
+		assert!((&mut pruning).now_or_never().is_none());
+
+		let block = client.new_block(Default::default()).unwrap().build().unwrap().block;
+		let hash = block.hash();
+		client.import(BlockOrigin::Own, block).unwrap();
+		client.finalize_block(BlockId::Hash(hash), None).unwrap();
+		assert!((&mut pruning).now_or_never().is_none());
+
+		assert!(check(&header2).is_none());
+	}
 }
@@ -30,7 +30,10 @@ mod aux_schema;
 
 pub use slots::SlotInfo;
 use slots::Slots;
-pub use aux_schema::{check_equivocation, MAX_SLOT_CAPACITY, PRUNING_BOUND};
+pub use aux_schema::{
+	check_equivocation, check_equivocation_with_pruning, prune_finalized, prune_on_finality,
+	SlotHeaderPruning, MAX_SLOT_CAPACITY, PRUNING_BOUND,
+};
 
 use std::{fmt::Debug, ops::Deref, pin::Pin, sync::Arc, time::{Instant, Duration}};
 use codec::{Decode, Encode};
@@ -163,6 +166,10 @@ pub trait SimpleSlotWorker<B: BlockT> {
 	>;
 
 	/// Whether to force authoring if offline.
+	///
+	/// Unless forced, slots are skipped while the `SyncOracle` reports the node as offline and
+	/// there is more than one authority. Slots are always skipped while major syncing, see
+	/// [`start_slot_worker`].
 	fn force_authoring(&self) -> bool;
 
 	/// Returns whether the block production should back off.
@@ -175,6 +182,15 @@ pub trait SimpleSlotWorker<B: BlockT> {
 		false
 	}
 
+	/// Returns the slot the given header was authored in, if it can be determined.
+	///
+	/// This is used to detect that the local clock lags behind the chain, i.e. that the chain
+	/// head claims a slot that is not behind the local one. By default no slot is returned and
+	/// this check is skipped.
+	fn header_slot(&self, _header: &B::Header) -> Option<Slot> {
+		None
+	}
+
 	/// Returns a handle to a `SyncOracle`.
 	fn sync_oracle(&mut self) -> &mut Self::SyncOracle;
 
@@ -211,6 +227,12 @@ pub trait SimpleSlotWorker<B: BlockT> {
 	{
 		let (timestamp, slot) = (slot_info.timestamp, slot_info.slot);
 
+		if let Some(head_slot) = self.header_slot(&chain_head) {
+			if skip_on_clock_drift(head_slot, slot, self.logging_target()) {
+				return Box::pin(future::ready(None));
+			}
+		}
+
 		let slot_remaining_duration = self.slot_remaining_duration(&slot_info);
 		let proposing_remaining_duration = self.proposing_remaining_duration(&chain_head, &slot_info);
 
@@ -616,6 +638,34 @@ pub fn slot_lenience_linear(parent_slot: Slot, slot_info: &SlotInfo) -> Option<D
 	}
 }
 
+/// Checks the slot of the chain head against the local slot and returns whether authorship must
+/// be skipped.
+///
+/// Blocks built on top of a head that is not behind the local slot are invalid, since slots must
+/// increase along the chain. It usually means that the local clock lags behind the rest of the
+/// network, or that the head was authored locally in this slot before a restart.
+fn skip_on_clock_drift(head_slot: Slot, slot: Slot, logging_target: &str) -> bool {
+	if head_slot < slot {
+		return false;
+	}
+
+	warn!(
+		target: logging_target,
+		"Chain head is at slot {} while the local slot is {}. \
+		The local clock is probably behind, skipping authorship.",
+		head_slot,
+		slot,
+	);
+
+	telemetry!(
+		CONSENSUS_WARN; "slots.clock_drift";
+		"head_slot" => *head_slot,
+		"slot" => *slot,
+	);
+
+	true
+}
+
 /// Trait for providing the strategy for when to backoff block authoring.
 pub trait BackoffAuthoringBlocksStrategy<N> {
 	/// Returns true if we should backoff authoring new blocks.
@@ -733,6 +783,16 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn clock_drift_guard_skips_heads_not_behind_the_local_slot() {
+		// a head behind the local slot is fine.
+		assert!(!super::skip_on_clock_drift(4.into(), 5.into(), "test"));
+
+		// a head in the same or a later slot is refused.
+		assert!(super::skip_on_clock_drift(5.into(), 5.into(), "test"));
+		assert!(super::skip_on_clock_drift(6.into(), 5.into(), "test"));
+	}
+
 	#[test]
 	fn linear_slot_lenience() {
 		// if no slots are skipped there should be no lenience
@@ -28,17 +28,6 @@ use sp_inherents::{InherentData, InherentDataProviders};
 use std::{pin::Pin, time::{Duration, Instant}};
 use futures_timer::Delay;
 
-/// Returns current duration since unix epoch.
-pub fn duration_now() -> Duration {
-	use std::time::SystemTime;
-	let now = SystemTime::now();
-	now.duration_since(SystemTime::UNIX_EPOCH).unwrap_or_else(|e| panic!(
-		"Current time {:?} is before unix epoch. Something is wrong: {:?}",
-		now,
-		e,
-	))
-}
-
 /// Returns the duration until the next slot, based on current duration since
 pub fn time_until_next(now: Duration, slot_duration: u64) -> Duration {
 	let remaining_full_millis = slot_duration - (now.as_millis() as u64 % slot_duration) - 1;
@@ -60,6 +49,10 @@ pub struct SlotInfo {
 }
 
 /// A stream that returns every time there is a new slot.
+///
+/// Slots are timed solely from the timestamp found in the inherent data, so the clock can be
+/// swapped (e.g. for a mock clock in tests) by registering a different timestamp inherent data
+/// provider.
 pub(crate) struct Slots<SC> {
 	last_slot: Slot,
 	slot_duration: u64,
@@ -93,8 +86,18 @@ impl<SC: SlotCompatible> Stream for Slots<SC> {
 			let slot_duration = self.slot_duration;
 			self.inner_delay = match self.inner_delay.take() {
 				None => {
-					// schedule wait.
-					let wait_dur = time_until_next(duration_now(), slot_duration);
+					// schedule wait, using the same time source as the slots themselves.
+					let inherent_data = match self.inherent_data_providers.create_inherent_data() {
+						Ok(id) => id,
+						Err(err) => return Poll::Ready(Some(Err(sp_consensus::Error::InherentData(err)))),
+					};
+					let (timestamp, _, offset) =
+						match self.timestamp_extractor.extract_timestamp_and_slot(&inherent_data) {
+							Ok(v) => v,
+							Err(err) => return Poll::Ready(Some(Err(err))),
+						};
+					let wait_dur = offset +
+						time_until_next(Duration::from_millis(timestamp), slot_duration);
 					Some(Delay::new(wait_dur))
 				}
 				Some(d) => Some(d),
@@ -141,3 +144,67 @@ impl<SC: SlotCompatible> Stream for Slots<SC> {
 }
 
 impl<SC> Unpin for Slots<SC> {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use sp_inherents::{InherentIdentifier, ProvideInherentData};
+	use std::sync::{Arc, atomic::{AtomicU64, Ordering}};
+
+	const SLOT_DURATION: u64 = 100;
+	const TIMESTAMP_IDENTIFIER: InherentIdentifier = *b"testtime";
+
+	/// A timestamp inherent data provider reading a clock that the test moves by hand.
+	struct MockClock(Arc<AtomicU64>);
+
+	impl ProvideInherentData for MockClock {
+		fn inherent_identifier(&self) -> &'static InherentIdentifier {
+			&TIMESTAMP_IDENTIFIER
+		}
+
+		fn provide_inherent_data(
+			&self,
+			inherent_data: &mut InherentData,
+		) -> Result<(), sp_inherents::Error> {
+			inherent_data.put_data(TIMESTAMP_IDENTIFIER, &self.0.load(Ordering::SeqCst))
+		}
+
+		fn error_to_string(&self, _: &[u8]) -> Option<String> {
+			None
+		}
+	}
+
+	struct MockSlotCompatible;
+
+	impl SlotCompatible for MockSlotCompatible {
+		fn extract_timestamp_and_slot(
+			&self,
+			inherent: &InherentData,
+		) -> Result<(u64, Slot, Duration), Error> {
+			let timestamp: u64 = inherent.get_data(&TIMESTAMP_IDENTIFIER)
+				.map_err(Error::InherentData)?
+				.expect("the mock clock always provides a timestamp");
+			Ok((timestamp, (timestamp / SLOT_DURATION).into(), Duration::from_millis(0)))
+		}
+	}
+
+	#[test]
+	fn slots_follow_the_timestamp_inherent() {
+		let clock = Arc::new(AtomicU64::new(10 * SLOT_DURATION));
+		let inherent_data_providers = InherentDataProviders::new();
+		inherent_data_providers.register_provider(MockClock(clock.clone())).unwrap();
+
+		let mut slots = Slots::new(SLOT_DURATION, inherent_data_providers, MockSlotCompatible);
+
+		let slot_info = futures::executor::block_on(slots.next()).unwrap().unwrap();
+		assert_eq!(slot_info.slot, 10.into());
+		assert_eq!(slot_info.timestamp, 10 * SLOT_DURATION);
+
+		// jumping the clock forward skips the slots in between.
+		clock.store(20 * SLOT_DURATION + 50, Ordering::SeqCst);
+
+		let slot_info = futures::executor::block_on(slots.next()).unwrap().unwrap();
+		assert_eq!(slot_info.slot, 20.into());
+		assert_eq!(slot_info.timestamp, 20 * SLOT_DURATION + 50);
+	}
+}
@@ -1522,6 +1522,13 @@ impl<Block: BlockT> Backend<Block> {
 						columns::BODY,
 						BlockId::<Block>::number(number),
 					)?;
+					utils::remove_from_db(
+						transaction,
+						&*self.storage.db,
+						columns::KEY_LOOKUP,
+						columns::JUSTIFICATION,
+						BlockId::<Block>::number(number),
+					)?;
 					match self.transaction_storage {
 						TransactionStorageMode::BlockBody => {},
 						TransactionStorageMode::StorageChain => {
@@ -1850,9 +1857,7 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 			Ok(ref hdr) => {
 				if !self.have_state_at(&hash, hdr.number) {
 					return Err(
-						sp_blockchain::Error::UnknownBlock(
-							format!("State already discarded for {:?}", block)
-						)
+						sp_blockchain::Error::StateAlreadyDiscarded(format!("{:?}", block))
 					)
 				}
 				if let Ok(()) = self.storage.state_db.pin(&hash) {
@@ -1876,9 +1881,7 @@ impl<Block: BlockT> sc_client_api::backend::Backend<Block> for Backend<Block> {
 					))
 				} else {
 					Err(
-						sp_blockchain::Error::UnknownBlock(
-							format!("State already discarded for {:?}", block)
-						)
+						sp_blockchain::Error::StateAlreadyDiscarded(format!("{:?}", block))
 					)
 				}
 			},
@@ -2601,7 +2604,7 @@ pub(crate) mod tests {
 				let mut op = backend.begin_operation().unwrap();
 				backend.begin_state_operation(&mut op, BlockId::Hash(blocks[4])).unwrap();
 				for i in 1 .. 5 {
-					op.mark_finalized(BlockId::Hash(blocks[i]), None).unwrap();
+					op.mark_finalized(BlockId::Hash(blocks[i]), Some(vec![i as u8])).unwrap();
 				}
 				backend.commit_operation(op).unwrap();
 			}
@@ -2611,6 +2614,37 @@ pub(crate) mod tests {
 			assert_eq!(None, bc.body(BlockId::hash(blocks[2])).unwrap());
 			assert_eq!(Some(vec![3.into()]), bc.body(BlockId::hash(blocks[3])).unwrap());
 			assert_eq!(Some(vec![4.into()]), bc.body(BlockId::hash(blocks[4])).unwrap());
+			assert_eq!(None, bc.justification(BlockId::hash(blocks[1])).unwrap());
+			assert_eq!(None, bc.justification(BlockId::hash(blocks[2])).unwrap());
+			assert_eq!(Some(vec![3]), bc.justification(BlockId::hash(blocks[3])).unwrap());
+			assert!(bc.header(BlockId::hash(blocks[1])).unwrap().is_some());
+		}
+	}
+
+	#[test]
+	fn discarded_state_is_reported() {
+		let backend = Backend::<Block>::new_test(2, 0);
+		let mut blocks = Vec::new();
+		let mut prev_hash = Default::default();
+		for i in 0 .. 5 {
+			let hash = insert_block(&backend, i, prev_hash, None, Default::default(), vec![i.into()]);
+			blocks.push(hash);
+			prev_hash = hash;
 		}
+
+		{
+			let mut op = backend.begin_operation().unwrap();
+			backend.begin_state_operation(&mut op, BlockId::Hash(blocks[4])).unwrap();
+			for i in 1 .. 5 {
+				op.mark_finalized(BlockId::Hash(blocks[i]), None).unwrap();
+			}
+			backend.commit_operation(op).unwrap();
+		}
+
+		assert!(matches!(
+			backend.state_at(BlockId::Hash(blocks[0])),
+			Err(sp_blockchain::Error::StateAlreadyDiscarded(_)),
+		));
+		assert!(backend.state_at(BlockId::Hash(blocks[4])).is_ok());
 	}
 }
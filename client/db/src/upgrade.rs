@@ -63,7 +63,7 @@ fn migrate_1_to_2<Block: BlockT>(db_path: &Path, _db_type: DatabaseType) -> sp_b
 }
 
 /// Reads current database version from the file at given path.
-/// If the file does not exist returns 0.
+/// If the file does not exist returns 0. Surrounding whitespace is ignored.
 fn current_version(path: &Path) -> sp_blockchain::Result<u32> {
 	let unknown_version_err = || sp_blockchain::Error::Backend("Unknown database version".into());
 
@@ -73,7 +73,7 @@ fn current_version(path: &Path) -> sp_blockchain::Result<u32> {
 		Ok(mut file) => {
 			let mut s = String::new();
 			file.read_to_string(&mut s).map_err(|_| unknown_version_err())?;
-			u32::from_str_radix(&s, 10).map_err(|_| unknown_version_err())
+			u32::from_str_radix(s.trim(), 10).map_err(|_| unknown_version_err())
 		},
 	}
 }
@@ -132,6 +132,14 @@ mod tests {
 		assert!(open_database(db_dir.path()).is_err());
 	}
 
+	#[test]
+	fn version_file_with_trailing_newline_is_read() {
+		let db_dir = tempfile::TempDir::new().unwrap();
+		fs::create_dir_all(db_dir.path()).unwrap();
+		fs::write(version_file_path(db_dir.path()), format!("{}\n", CURRENT_VERSION)).unwrap();
+		assert_eq!(current_version(db_dir.path()).unwrap(), CURRENT_VERSION);
+	}
+
 	#[test]
 	fn open_empty_database_works() {
 		let db_dir = tempfile::TempDir::new().unwrap();
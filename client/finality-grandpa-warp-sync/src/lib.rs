@@ -60,6 +60,7 @@ const LOG_TARGET: &str = "finality-grandpa-warp-sync-request-handler";
 pub fn generate_request_response_config(protocol_id: ProtocolId) -> RequestResponseConfig {
 	RequestResponseConfig {
 		name: generate_protocol_name(protocol_id).into(),
+		fallback_names: Vec::new(),
 		max_request_size: 32,
 		max_response_size: 16 * 1024 * 1024,
 		request_timeout: Duration::from_secs(10),
@@ -367,6 +367,46 @@ mod tests {
 		assert_eq!(io.handle_request_sync(request, meta), Some(response.into()));
 	}
 
+	#[test]
+	fn background_rounds_are_reported_in_order() {
+		struct ManyRoundsVoterState;
+
+		impl ReportVoterState for ManyRoundsVoterState {
+			fn get(&self) -> Option<report::VoterState<AuthorityId>> {
+				let round_state = sc_finality_grandpa::report::RoundState {
+					total_weight: 100_u64.try_into().unwrap(),
+					threshold_weight: 67_u64.try_into().unwrap(),
+					prevote_current_weight: 100.into(),
+					prevote_ids: voters(),
+					precommit_current_weight: 100.into(),
+					precommit_ids: voters(),
+				};
+
+				let background_rounds = vec![3, 1, 4, 2]
+					.into_iter()
+					.map(|round| (round, round_state.clone()))
+					.collect();
+
+				Some(report::VoterState {
+					background_rounds,
+					best_round: (5, round_state),
+				})
+			}
+		}
+
+		let round_states = ReportedRoundStates::from(&TestAuthoritySet, &ManyRoundsVoterState)
+			.ok()
+			.expect("Voter state is available");
+		let rounds = serde_json::to_value(&round_states).unwrap()["background"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|round_state| round_state["round"].as_u64().unwrap())
+			.collect::<Vec<_>>();
+
+		assert_eq!(rounds, vec![1, 2, 3, 4]);
+	}
+
 	fn setup_session() -> (sc_rpc::Metadata, jsonrpc_core::futures::sync::mpsc::Receiver<String>) {
 		let (tx, rx) = jsonrpc_core::futures::sync::mpsc::channel(1);
 		let meta = sc_rpc::Metadata::new(tx);
@@ -146,12 +146,15 @@ impl ReportedRoundStates {
 			RoundState::from(round, &round_state, &current_voters)?
 		};
 
-		let background = voter_state
+		let mut background = voter_state
 			.background_rounds
 			.iter()
 			.map(|(round, round_state)| RoundState::from(*round, round_state, &current_voters))
 			.collect::<Result<Vec<_>, Error>>()?;
 
+		// Background rounds are kept in a map, report them in a stable order.
+		background.sort_by_key(|round_state| round_state.round);
+
 		Ok(Self {
 			set_id,
 			best,
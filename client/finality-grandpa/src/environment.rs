@@ -1113,7 +1113,9 @@ where
 		finalize_block(
 			self.client.clone(),
 			&self.authority_set,
-			Some(self.config.justification_period.into()),
+			Some(self.config.justification_period)
+				.filter(|period| *period != 0)
+				.map(Into::into),
 			hash,
 			number,
 			(round, commit).into(),
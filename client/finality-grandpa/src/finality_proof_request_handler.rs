@@ -0,0 +1,260 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helper for handling (i.e. answering) GRANDPA finality proof requests from a remote peer via the
+//! `RequestResponsesBehaviour` of `sc-network`.
+//!
+//! A request is the SCALE-encoded number of the block to prove finality for. The response is the
+//! SCALE-encoded `Option` of an encoded [`FinalityProof`](crate::FinalityProof), `None` meaning
+//! that no justification is available to prove finality of the requested block.
+
+use std::{sync::Arc, time::Duration};
+
+use finality_grandpa::BlockNumberOps;
+use futures::{channel::{mpsc, oneshot}, stream::StreamExt};
+use log::debug;
+use parity_scale_codec::{Decode, Encode};
+use sc_client_api::backend::Backend;
+use sc_network::config::{IncomingRequest, OutgoingResponse, ProtocolId, RequestResponseConfig};
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+
+use crate::{FinalityProofError, FinalityProofProvider};
+
+const LOG_TARGET: &str = "finality-proof-request-handler";
+
+/// Generates a [`RequestResponseConfig`] for the GRANDPA finality proof request protocol, refusing
+/// incoming requests.
+fn generate_protocol_config(protocol_id: &ProtocolId) -> RequestResponseConfig {
+	RequestResponseConfig {
+		name: generate_protocol_name(protocol_id).into(),
+		fallback_names: Vec::new(),
+		max_request_size: 32,
+		max_response_size: 16 * 1024 * 1024,
+		request_timeout: Duration::from_secs(15),
+		inbound_queue: None,
+	}
+}
+
+/// Generate the GRANDPA finality proof protocol name from chain specific protocol identifier.
+fn generate_protocol_name(protocol_id: &ProtocolId) -> String {
+	let mut s = String::new();
+	s.push_str("/");
+	s.push_str(protocol_id.as_ref());
+	s.push_str("/grandpa/finality-proof/1");
+	s
+}
+
+/// Handler for incoming GRANDPA finality proof requests from a remote peer.
+pub struct FinalityProofRequestHandler<BE, Block: BlockT> {
+	finality_proof_provider: Arc<FinalityProofProvider<BE, Block>>,
+	request_receiver: mpsc::Receiver<IncomingRequest>,
+}
+
+impl<BE, Block> FinalityProofRequestHandler<BE, Block>
+where
+	Block: BlockT,
+	NumberFor<Block>: BlockNumberOps,
+	BE: Backend<Block> + Send + Sync + 'static,
+{
+	/// Create a new [`FinalityProofRequestHandler`].
+	pub fn new(
+		protocol_id: &ProtocolId,
+		finality_proof_provider: Arc<FinalityProofProvider<BE, Block>>,
+	) -> (Self, RequestResponseConfig) {
+		// Light clients only request finality proofs when catching up with authority set changes,
+		// thus a small queue is enough.
+		let (tx, request_receiver) = mpsc::channel(20);
+
+		let mut protocol_config = generate_protocol_config(protocol_id);
+		protocol_config.inbound_queue = Some(tx);
+
+		(Self { finality_proof_provider, request_receiver }, protocol_config)
+	}
+
+	/// Run [`FinalityProofRequestHandler`].
+	pub async fn run(mut self) {
+		while let Some(request) = self.request_receiver.next().await {
+			let IncomingRequest { peer, payload, pending_response } = request;
+
+			match self.handle_request(payload, pending_response) {
+				Ok(()) => debug!(target: LOG_TARGET, "Handled finality proof request from {}.", peer),
+				Err(e) => debug!(
+					target: LOG_TARGET,
+					"Failed to handle finality proof request from {}: {}",
+					peer, e,
+				),
+			}
+		}
+	}
+
+	fn handle_request(
+		&self,
+		payload: Vec<u8>,
+		pending_response: oneshot::Sender<OutgoingResponse>
+	) -> Result<(), HandleRequestError> {
+		let block = NumberFor::<Block>::decode(&mut &payload[..])?;
+
+		let result = match self.finality_proof_provider.prove_finality(block) {
+			Ok(proof) => Ok(proof.encode()),
+			Err(e) => {
+				// Let the remote know that we can't answer, but don't punish it, the block might
+				// just not be finalized yet on our side.
+				let _ = pending_response.send(OutgoingResponse {
+					result: Err(()),
+					reputation_changes: Vec::new(),
+				});
+				return Err(e.into());
+			}
+		};
+
+		pending_response.send(OutgoingResponse {
+			result,
+			reputation_changes: Vec::new(),
+		}).map_err(|_| HandleRequestError::SendResponse)
+	}
+}
+
+#[derive(derive_more::Display, derive_more::From)]
+enum HandleRequestError {
+	#[display(fmt = "Failed to decode block number: {}.", _0)]
+	DecodeScale(parity_scale_codec::Error),
+	#[display(fmt = "Failed to prove finality: {}.", _0)]
+	FinalityProof(FinalityProofError),
+	#[display(fmt = "Failed to send response.")]
+	SendResponse,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::authorities::{AuthoritySet, AuthoritySetChanges, SharedAuthoritySet};
+	use fork_tree::ForkTree;
+	use futures::executor::block_on;
+	use sc_client_api::{in_mem::Backend as InMemoryBackend, NewBlockState};
+	use sp_core::crypto::Public;
+	use sp_finality_grandpa::AuthorityId;
+	use sp_runtime::traits::Header as HeaderT;
+	use substrate_test_runtime_client::runtime::{Block, Header, H256};
+
+	type FinalityProof = crate::FinalityProof<Header>;
+
+	fn header(number: u64) -> Header {
+		let parent_hash = match number {
+			0 => Default::default(),
+			_ => header(number - 1).hash(),
+		};
+		Header::new(
+			number,
+			H256::from_low_u64_be(0),
+			H256::from_low_u64_be(0),
+			parent_hash,
+			Default::default(),
+		)
+	}
+
+	/// Creates a handler backed by a chain finalized up to #3, with a justification at #2 ending
+	/// authority set 0.
+	fn handler(
+		with_authority_set: bool,
+	) -> FinalityProofRequestHandler<InMemoryBackend<Block>, Block> {
+		let backend = Arc::new(InMemoryBackend::<Block>::new());
+		let justifications = vec![Some(vec![0]), None, Some(vec![2]), None];
+		for (number, justification) in justifications.into_iter().enumerate() {
+			let header = header(number as u64);
+			backend.blockchain()
+				.insert(header.hash(), header, justification, None, NewBlockState::Final)
+				.unwrap();
+		}
+
+		let shared_authority_set = if with_authority_set {
+			let mut authority_set_changes = AuthoritySetChanges::empty();
+			authority_set_changes.append(0, 2);
+			let authority_set = AuthoritySet::new(
+				vec![(AuthorityId::from_slice(&[1u8; 32]), 1)],
+				1,
+				ForkTree::new(),
+				Vec::new(),
+				authority_set_changes,
+			).unwrap();
+			Some(SharedAuthoritySet::from(authority_set))
+		} else {
+			None
+		};
+
+		let (handler, _) = FinalityProofRequestHandler::new(
+			&ProtocolId::from("test"),
+			FinalityProofProvider::new_for_service(backend, shared_authority_set),
+		);
+		handler
+	}
+
+	fn handle(
+		handler: &FinalityProofRequestHandler<InMemoryBackend<Block>, Block>,
+		payload: Vec<u8>,
+	) -> (Result<(), HandleRequestError>, Option<OutgoingResponse>) {
+		let (tx, rx) = oneshot::channel();
+		let result = handler.handle_request(payload, tx);
+		(result, block_on(rx).ok())
+	}
+
+	#[test]
+	fn answers_with_finality_proof() {
+		let (result, response) = handle(&handler(true), 1u64.encode());
+		assert!(result.is_ok());
+
+		let response = response.unwrap().result.unwrap();
+		let proof = Option::<Vec<u8>>::decode(&mut &response[..]).unwrap().unwrap();
+		assert_eq!(
+			FinalityProof::decode(&mut &proof[..]).unwrap(),
+			FinalityProof {
+				block: header(2).hash(),
+				justification: vec![2],
+				unknown_headers: Vec::new(),
+			},
+		);
+	}
+
+	#[test]
+	fn answers_with_none_without_authority_set() {
+		let (result, response) = handle(&handler(false), 1u64.encode());
+		assert!(result.is_ok());
+
+		let response = response.unwrap().result.unwrap();
+		assert_eq!(Option::<Vec<u8>>::decode(&mut &response[..]).unwrap(), None);
+	}
+
+	#[test]
+	fn refuses_request_for_block_not_yet_finalized() {
+		let (result, response) = handle(&handler(true), 3u64.encode());
+		assert!(matches!(
+			result,
+			Err(HandleRequestError::FinalityProof(FinalityProofError::BlockNotYetFinalized)),
+		));
+
+		let response = response.unwrap();
+		assert_eq!(response.result, Err(()));
+		assert!(response.reputation_changes.is_empty());
+	}
+
+	#[test]
+	fn drops_undecodable_request() {
+		let (result, response) = handle(&handler(true), vec![1]);
+		assert!(matches!(result, Err(HandleRequestError::DecodeScale(_))));
+		assert!(response.is_none());
+	}
+}
@@ -114,6 +114,7 @@ mod aux_schema;
 mod communication;
 mod environment;
 mod finality_proof;
+mod finality_proof_request_handler;
 mod import;
 mod justification;
 mod notification;
@@ -123,6 +124,7 @@ mod voting_rule;
 
 pub use authorities::{SharedAuthoritySet, AuthoritySet};
 pub use finality_proof::{FinalityProof, FinalityProofProvider, FinalityProofError};
+pub use finality_proof_request_handler::FinalityProofRequestHandler;
 pub use notification::{GrandpaJustificationSender, GrandpaJustificationStream};
 pub use import::GrandpaBlockImport;
 pub use justification::GrandpaJustification;
@@ -257,7 +259,8 @@ pub struct Config {
 	pub gossip_duration: Duration,
 	/// Justification generation period (in blocks). GRANDPA will try to generate justifications
 	/// at least every justification_period blocks. There are some other events which might cause
-	/// justification generation.
+	/// justification generation. A period of `0` disables periodic justifications, so they are only
+	/// generated for those other events (e.g. authority set changes).
 	pub justification_period: u32,
 	/// Whether the GRANDPA observer protocol is live on the network and thereby
 	/// a full-node not running as a validator is running the GRANDPA observer
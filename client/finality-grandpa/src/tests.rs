@@ -1593,6 +1593,39 @@ fn imports_justification_for_regular_blocks_on_import() {
 	);
 }
 
+#[test]
+fn grandpa_environment_finalizes_with_periodic_justifications_disabled() {
+	use finality_grandpa::voter::Environment;
+
+	let peers = &[Ed25519Keyring::Alice];
+	let voters = make_ids(peers);
+
+	let mut net = GrandpaTestNet::new(TestApi::new(voters), 1);
+	let peer = net.peer(0);
+	let network_service = peer.network_service().clone();
+	let link = peer.data.lock().take().unwrap();
+	let client = peer.client().clone();
+
+	// add 32 blocks, so that the last one would fall on a justification period boundary
+	peer.push_blocks(32, false);
+
+	// a justification period of zero disables periodic justifications
+	let mut environment = test_environment(&link, None, network_service, ());
+	environment.config.justification_period = 0;
+
+	let target_hash = client.header(&BlockId::Number(32)).unwrap().unwrap().hash();
+	let commit = finality_grandpa::Commit {
+		target_hash,
+		target_number: 32,
+		precommits: Vec::new(),
+	};
+
+	environment.finalize_block(target_hash, 32, 1, commit).unwrap();
+
+	assert_eq!(client.info().finalized_number, 32);
+	assert!(client.justification(&BlockId::Number(32)).unwrap().is_none());
+}
+
 #[test]
 fn grandpa_environment_doesnt_send_equivocation_reports_for_itself() {
 	use finality_grandpa::voter::Environment;
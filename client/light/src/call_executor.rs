@@ -32,7 +32,7 @@ use sp_runtime::{
 use sp_externalities::Extensions;
 use sp_state_machine::{
 	self, Backend as StateBackend, OverlayedChanges, ExecutionStrategy, create_proof_check_backend,
-	execution_proof_check_on_trie_backend, ExecutionManager, StorageProof,
+	execution_proof_check_on_trie_backend, ExecutionManager, StorageProof, StorageCollection,
 };
 use hash_db::Hasher;
 
@@ -96,6 +96,23 @@ impl<Block, B, Local> CallExecutor<Block> for
 		}
 	}
 
+	fn call_with_overrides(
+		&self,
+		id: &BlockId<Block>,
+		method: &str,
+		call_data: &[u8],
+		overrides: StorageCollection,
+		strategy: ExecutionStrategy,
+		extensions: Option<Extensions>,
+	) -> ClientResult<(Vec<u8>, StorageCollection)> {
+		match self.backend.is_local_state_available(id) {
+			true => self.local.call_with_overrides(
+				id, method, call_data, overrides, strategy, extensions,
+			),
+			false => Err(ClientError::NotAvailableOnLightClient),
+		}
+	}
+
 	fn contextual_call<
 		'a,
 		IB: Fn() -> ClientResult<()>,
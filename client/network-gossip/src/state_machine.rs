@@ -215,6 +215,10 @@ impl<B: BlockT> ConsensusGossip<B> {
 		sender: Option<PeerId>,
 	) {
 		if self.known_messages.put(message_hash.clone(), ()).is_none() {
+			if let Some(limit) = self.validator.max_messages_per_topic() {
+				self.enforce_topic_limit(topic, limit);
+			}
+
 			self.messages.push(MessageEntry {
 				message_hash,
 				topic,
@@ -228,6 +232,32 @@ impl<B: BlockT> ConsensusGossip<B> {
 		}
 	}
 
+	/// Drop the oldest messages of `topic` so that there is room for a new one
+	/// within `limit`.
+	fn enforce_topic_limit(&mut self, topic: B::Hash, limit: usize) {
+		let mut in_topic = self.messages.iter().filter(|entry| entry.topic == topic).count();
+		if in_topic < limit {
+			return;
+		}
+
+		let before = self.messages.len();
+		self.messages.retain(|entry| {
+			if entry.topic == topic && in_topic >= limit {
+				in_topic -= 1;
+				false
+			} else {
+				true
+			}
+		});
+		let dropped = before - self.messages.len();
+
+		if let Some(ref metrics) = self.metrics {
+			metrics.expired_messages.inc_by(dropped as u64)
+		}
+
+		trace!(target: "gossip", "Dropped {} messages exceeding the limit of topic {:?}", dropped, topic);
+	}
+
 	/// Registers a message without propagating it to any peers. The message
 	/// becomes available to new peers or when the service is asked to gossip
 	/// the message's topic. No validation is performed on the message, if the
@@ -653,6 +683,41 @@ mod tests {
 		assert_eq!(consensus.messages.len(), 2);
 	}
 
+	#[test]
+	fn drops_oldest_messages_above_topic_limit() {
+		struct LimitTwo;
+		impl Validator<Block> for LimitTwo {
+			fn validate(
+				&self,
+				_context: &mut dyn ValidatorContext<Block>,
+				_sender: &PeerId,
+				_data: &[u8],
+			) -> ValidationResult<H256> {
+				ValidationResult::ProcessAndKeep(H256::default())
+			}
+
+			fn max_messages_per_topic(&self) -> Option<usize> {
+				Some(2)
+			}
+		}
+
+		let mut consensus = ConsensusGossip::<Block>::new(Arc::new(LimitTwo), "/foo".into(), None);
+
+		let topic = [1; 32].into();
+		let other_topic = [2; 32].into();
+
+		consensus.register_message(other_topic, vec![0]);
+		consensus.register_message(topic, vec![1]);
+		consensus.register_message(topic, vec![2]);
+		consensus.register_message(topic, vec![3]);
+
+		assert_eq!(
+			consensus.messages_for(topic).map(|n| n.message).collect::<Vec<_>>(),
+			vec![vec![2], vec![3]],
+		);
+		assert_eq!(consensus.messages_for(other_topic).count(), 1);
+	}
+
 	#[test]
 	fn peer_is_removed_on_disconnect() {
 		let mut consensus = ConsensusGossip::<Block>::new(Arc::new(AllowAll), "/foo".into(), None);
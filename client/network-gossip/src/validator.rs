@@ -46,6 +46,14 @@ pub trait Validator<B: BlockT>: Send + Sync {
 	fn message_allowed<'a>(&'a self) -> Box<dyn FnMut(&PeerId, MessageIntent, &B::Hash, &[u8]) -> bool + 'a> {
 		Box::new(move |_who, _intent, _topic, _data| true)
 	}
+
+	/// Maximum number of messages kept for a single topic.
+	///
+	/// Once the limit is reached, registering a new message on the topic drops the oldest one.
+	/// `None` means no limit.
+	fn max_messages_per_topic(&self) -> Option<usize> {
+		None
+	}
 }
 
 /// Validation context. Allows reacting to incoming messages by sending out further messages.
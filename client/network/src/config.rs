@@ -408,6 +408,8 @@ pub struct NetworkConfiguration {
 	pub transport: TransportConfig,
 	/// Maximum number of peers to ask the same blocks in parallel.
 	pub max_parallel_downloads: u32,
+	/// Maximum number of light client requests in flight at the same time.
+	pub max_parallel_light_client_requests: u32,
 
 	/// True if Kademlia random discovery should be enabled.
 	///
@@ -470,6 +472,7 @@ impl NetworkConfiguration {
 				wasm_external_transport: None,
 			},
 			max_parallel_downloads: 5,
+			max_parallel_light_client_requests: 16,
 			enable_dht_random_walk: true,
 			allow_non_globals_in_dht: false,
 			kademlia_disjoint_query_paths: false,
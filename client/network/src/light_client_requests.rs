@@ -41,6 +41,7 @@ fn generate_protocol_name(protocol_id: &ProtocolId) -> String {
 pub fn generate_protocol_config(protocol_id: &ProtocolId) -> ProtocolConfig {
 	ProtocolConfig {
 		name: generate_protocol_name(protocol_id).into(),
+		fallback_names: Vec::new(),
 		max_request_size: 1 * 1024 * 1024,
 		max_response_size: 16 * 1024 * 1024,
 		request_timeout: Duration::from_secs(15),
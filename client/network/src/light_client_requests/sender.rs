@@ -49,7 +49,7 @@ use sp_runtime::{
 	traits::{Block, Header, NumberFor},
 };
 use std::{
-	collections::{BTreeMap, VecDeque, HashMap},
+	collections::{BTreeMap, VecDeque, HashMap, HashSet},
 	pin::Pin,
 	sync::Arc,
 	task::{Context, Poll},
@@ -64,10 +64,14 @@ mod rep {
 	pub const REFUSED: ReputationChange = ReputationChange::new(-(1 << 8), "light client request refused");
 }
 
+/// Default maximum number of requests that can be in flight at the same time.
+pub const DEFAULT_MAX_PARALLEL_REQUESTS: usize = 16;
+
 /// Configuration options for [`LightClientRequestSender`].
 #[derive(Debug, Clone)]
 struct Config {
 	max_pending_requests: usize,
+	max_parallel_requests: usize,
 	light_protocol: String,
 	block_protocol: String,
 }
@@ -77,6 +81,7 @@ impl Config {
 	pub fn new(id: &ProtocolId) -> Self {
 		Config {
 			max_pending_requests: 128,
+			max_parallel_requests: DEFAULT_MAX_PARALLEL_REQUESTS,
 			light_protocol: super::generate_protocol_name(id),
 			block_protocol: crate::block_request_handler::generate_protocol_name(id),
 		}
@@ -106,6 +111,8 @@ pub struct LightClientRequestSender<B: Block> {
 struct PendingRequest<B: Block> {
 	/// Remaining attempts.
 	attempts_left: usize,
+	/// Peers that already failed to answer this request and must not be asked again.
+	failed_peers: HashSet<PeerId>,
 	/// The actual request.
 	request: Request<B>,
 }
@@ -115,6 +122,7 @@ impl<B: Block> PendingRequest<B> {
 		PendingRequest {
 			// Number of retries + one for the initial attempt.
 			attempts_left: req.retries() + 1,
+			failed_peers: HashSet::new(),
 			request: req,
 		}
 	}
@@ -122,6 +130,7 @@ impl<B: Block> PendingRequest<B> {
 	fn into_sent(self, peer_id: PeerId) -> SentRequest<B> {
 		SentRequest {
 			attempts_left: self.attempts_left,
+			failed_peers: self.failed_peers,
 			request: self.request,
 			peer: peer_id,
 		}
@@ -133,6 +142,8 @@ impl<B: Block> PendingRequest<B> {
 struct SentRequest<B: Block> {
 	/// Remaining attempts.
 	attempts_left: usize,
+	/// Peers that previously failed to answer this request.
+	failed_peers: HashSet<PeerId>,
 	/// The actual request.
 	request: Request<B>,
 	/// The peer that the request is send to.
@@ -140,9 +151,15 @@ struct SentRequest<B: Block> {
 }
 
 impl<B: Block> SentRequest<B> {
+	/// Turn the request back into a pending one after the peer failed to answer it.
+	///
+	/// The peer is excluded from any further attempt of this request.
 	fn into_pending(self) -> PendingRequest<B> {
+		let mut failed_peers = self.failed_peers;
+		failed_peers.insert(self.peer);
 		PendingRequest {
 			attempts_left: self.attempts_left,
+			failed_peers,
 			request: self.request,
 		}
 	}
@@ -170,6 +187,12 @@ where
 		}
 	}
 
+	/// Set the maximum number of requests that can be in flight at the same time.
+	pub fn with_max_parallel_requests(mut self, max_parallel_requests: usize) -> Self {
+		self.config.max_parallel_requests = max_parallel_requests;
+		self
+	}
+
 	/// We rely on external information about peers best blocks as we lack the
 	/// means to determine it ourselves.
 	pub fn update_best_block(&mut self, peer: &PeerId, num: NumberFor<B>) {
@@ -474,44 +497,72 @@ impl<B: Block> Stream for LightClientRequestSender<B> {
 		}
 
 		// If we have a pending request to send, try to find an available peer and send it.
-		while let Some(mut pending_request) = self.pending_requests.pop_front() {
-			if pending_request.attempts_left == 0 {
+		let mut index = 0;
+		while index < self.pending_requests.len() {
+			if self.pending_requests[index].attempts_left == 0 {
+				let pending_request = self.pending_requests.remove(index)
+					.expect("`index` is smaller than the queue length; qed");
 				pending_request.request.return_reply(Err(ClientError::RemoteFetchFailed));
 				continue
 			}
 
-			let protocol = if pending_request.request.is_block_request() {
-				self.config.block_protocol.clone()
-			} else {
-				self.config.light_protocol.clone()
-			};
+			// Keep the request queued until one of the in-flight requests completes.
+			if self.sent_requests.len() >= self.config.max_parallel_requests {
+				log::trace!("Maximum number of parallel requests reached.");
+				index += 1;
+				continue;
+			}
 
-			// Out of all idle peers, find one who's best block is high enough, choose any idle peer
-			// if none exists.
-			let mut peer = None;
-			for (peer_id, peer_info) in self.peers.iter_mut() {
-				if peer_info.status == PeerStatus::Idle {
-					match peer_info.best_block {
-						Some(n) if n >= pending_request.request.required_block() => {
-							peer = Some((*peer_id, peer_info));
-							break
-						},
-						_ => peer = Some((*peer_id, peer_info))
+			// Out of all idle peers that did not fail this request before, find one who's best
+			// block is high enough, choose any such peer if none exists.
+			let peer = {
+				let pending_request = &self.pending_requests[index];
+				let required_block = pending_request.request.required_block();
+				let mut peer = None;
+				for (peer_id, peer_info) in self.peers.iter() {
+					if peer_info.status == PeerStatus::Idle
+						&& !pending_request.failed_peers.contains(peer_id)
+					{
+						match peer_info.best_block {
+							Some(n) if n >= required_block => {
+								peer = Some(*peer_id);
+								break
+							},
+							_ => peer = Some(*peer_id)
+						}
 					}
 				}
-			}
+				peer
+			};
 
-			// Break in case there is no idle peer.
-			let (peer_id, peer_info) = match peer {
-				Some((peer_id, peer_info)) => (peer_id, peer_info),
+			// Leave the request in the queue in case there is no suitable peer, unless every
+			// connected peer already failed it, in which case it would never be sent.
+			let peer_id = match peer {
+				Some(peer_id) => peer_id,
 				None => {
-					self.pending_requests.push_front(pending_request);
+					let failed_peers = &self.pending_requests[index].failed_peers;
+					if !self.peers.is_empty() && self.peers.keys().all(|p| failed_peers.contains(p)) {
+						log::debug!("All connected peers failed the request.");
+						let pending_request = self.pending_requests.remove(index)
+							.expect("`index` is smaller than the queue length; qed");
+						pending_request.request.return_reply(Err(ClientError::RemoteFetchFailed));
+						continue
+					}
 					log::debug!("No peer available to send request to.");
-
-					break;
+					index += 1;
+					continue;
 				}
 			};
 
+			let mut pending_request = self.pending_requests.remove(index)
+				.expect("`index` is smaller than the queue length; qed");
+
+			let protocol = if pending_request.request.is_block_request() {
+				self.config.block_protocol.clone()
+			} else {
+				self.config.light_protocol.clone()
+			};
+
 			let request_bytes = match pending_request.request.serialize_request() {
 				Ok(bytes) => bytes,
 				Err(error) => {
@@ -523,7 +574,9 @@ impl<B: Block> Stream for LightClientRequestSender<B> {
 
 			let (tx, rx) = oneshot::channel();
 
-			peer_info.status = PeerStatus::Busy;
+			if let Some(peer_info) = self.peers.get_mut(&peer_id) {
+				peer_info.status = PeerStatus::Busy;
+			}
 
 			pending_request.attempts_left -= 1;
 
@@ -1161,6 +1214,137 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn does_not_retry_request_with_failed_peer() {
+		let peer = PeerId::random();
+		let (_peer_set, peer_set_handle) = peerset();
+		let mut sender = LightClientRequestSender::<Block>::new(
+			&protocol_id(),
+			Arc::new(crate::light_client_requests::tests::DummyFetchChecker {
+				ok: true,
+				_mark: std::marker::PhantomData,
+			}),
+			peer_set_handle,
+		);
+
+		sender.inject_connected(peer);
+
+		let chan = oneshot::channel();
+		let request = light::RemoteCallRequest {
+			block: Default::default(),
+			header: dummy_header(),
+			method: "test".into(),
+			call_data: vec![],
+			retry_count: Some(1),
+		};
+		sender.request(Request::Call { request, sender: chan.0 }).unwrap();
+
+		let OutEvent::SendRequest { target, pending_response, .. } = block_on(sender.next()).unwrap();
+		assert_eq!(target, peer);
+		pending_response.send(Err(RequestFailure::Refused)).unwrap();
+
+		assert_matches!(
+			block_on(async { poll!(sender.next()) }), Poll::Pending,
+			"Expect sender to not issue another attempt, given that there is no peer left.",
+		);
+		assert_eq!(1, sender.pending_requests.len(), "Expect request to be pending again.");
+
+		// The peer reconnects together with a fresh one, only the fresh one is eligible.
+		let other_peer = PeerId::random();
+		sender.inject_connected(peer);
+		sender.inject_connected(other_peer);
+		let OutEvent::SendRequest { target, .. } = block_on(sender.next()).unwrap();
+		assert_eq!(target, other_peer);
+	}
+
+	#[test]
+	fn fails_request_once_every_connected_peer_failed_it() {
+		let peer = PeerId::random();
+		let (_peer_set, peer_set_handle) = peerset();
+		let mut sender = LightClientRequestSender::<Block>::new(
+			&protocol_id(),
+			Arc::new(crate::light_client_requests::tests::DummyFetchChecker {
+				ok: true,
+				_mark: std::marker::PhantomData,
+			}),
+			peer_set_handle,
+		);
+
+		sender.inject_connected(peer);
+
+		let chan = oneshot::channel();
+		let request = light::RemoteCallRequest {
+			block: Default::default(),
+			header: dummy_header(),
+			method: "test".into(),
+			call_data: vec![],
+			retry_count: Some(2),
+		};
+		sender.request(Request::Call { request, sender: chan.0 }).unwrap();
+
+		let OutEvent::SendRequest { target, pending_response, .. } = block_on(sender.next()).unwrap();
+		assert_eq!(target, peer);
+		pending_response.send(Err(RequestFailure::Refused)).unwrap();
+
+		assert_matches!(block_on(async { poll!(sender.next()) }), Poll::Pending);
+		assert_eq!(1, sender.pending_requests.len(), "Expect request to wait for a peer.");
+
+		// The only connected peer already failed the request, so it can never be sent.
+		sender.inject_connected(peer);
+		assert_matches!(block_on(async { poll!(sender.next()) }), Poll::Pending);
+		assert_eq!(0, sender.pending_requests.len());
+		assert_matches!(
+			block_on(chan.1).unwrap(),
+			Err(ClientError::RemoteFetchFailed),
+		);
+	}
+
+	#[test]
+	fn respects_max_parallel_requests() {
+		let peers = (0..3).map(|_| PeerId::random()).collect::<Vec<_>>();
+		let (_peer_set, peer_set_handle) = peerset();
+		let mut sender = LightClientRequestSender::<Block>::new(
+			&protocol_id(),
+			Arc::new(crate::light_client_requests::tests::DummyFetchChecker {
+				ok: true,
+				_mark: std::marker::PhantomData,
+			}),
+			peer_set_handle,
+		).with_max_parallel_requests(2);
+
+		for peer in &peers {
+			sender.inject_connected(*peer);
+		}
+
+		let mut receivers = Vec::new();
+		for _ in 0..3 {
+			let chan = oneshot::channel();
+			let request = light::RemoteCallRequest {
+				block: Default::default(),
+				header: dummy_header(),
+				method: "test".into(),
+				call_data: vec![],
+				retry_count: None,
+			};
+			sender.request(Request::Call { request, sender: chan.0 }).unwrap();
+			receivers.push(chan.1);
+		}
+
+		let OutEvent::SendRequest { .. } = block_on(sender.next()).unwrap();
+		let OutEvent::SendRequest { pending_response, .. } = block_on(sender.next()).unwrap();
+		assert_matches!(
+			block_on(async { poll!(sender.next()) }), Poll::Pending,
+			"Expect sender to not exceed the maximum number of parallel requests.",
+		);
+		assert_eq!(2, sender.sent_requests.len(), "Expect two requests in flight.");
+		assert_eq!(1, sender.pending_requests.len(), "Expect one request to be queued.");
+
+		// Completing one of the requests frees a slot for the queued one.
+		pending_response.send(Err(RequestFailure::Refused)).unwrap();
+		let OutEvent::SendRequest { .. } = block_on(sender.next()).unwrap();
+		assert_eq!(2, sender.sent_requests.len(), "Expect two requests in flight.");
+	}
+
 	fn issue_request(request: Request<Block>) {
 		let peer = PeerId::random();
 
@@ -44,6 +44,10 @@ pub struct NetworkState {
 	pub not_connected_peers: HashMap<String, NotConnectedPeer>,
 	/// State of the peerset manager.
 	pub peerset: serde_json::Value,
+	/// Total number of bytes received so far.
+	pub total_bytes_inbound: u64,
+	/// Total number of bytes sent so far.
+	pub total_bytes_outbound: u64,
 }
 
 /// Part of the `NetworkState` struct. Unstable.
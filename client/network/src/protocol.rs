@@ -552,6 +552,11 @@ impl<B: BlockT, H: ExHashT> Protocol<B, H> {
 		self.behaviour.num_discovered_peers()
 	}
 
+	/// Returns the list of reserved peers for syncing purposes.
+	pub fn reserved_peers(&self) -> impl Iterator<Item = &PeerId> {
+		self.behaviour.reserved_peers(HARDCODED_PEERSETS_SYNC)
+	}
+
 	/// Disconnects the given peer if we are connected to it.
 	pub fn disconnect_peer(&mut self, peer_id: &PeerId, protocol_name: &str) {
 		if let Some(position) = self.notification_protocols.iter().position(|p| *p == protocol_name) {
@@ -425,6 +425,11 @@ impl GenericProto {
 		self.peerset.num_discovered_peers()
 	}
 
+	/// Returns the list of reserved peers of the given set.
+	pub fn reserved_peers(&self, set_id: sc_peerset::SetId) -> impl Iterator<Item = &PeerId> {
+		self.peerset.reserved_peers(set_id)
+	}
+
 	/// Returns the list of all the peers we have an open channel to.
 	pub fn open_peers<'a>(&'a self) -> impl Iterator<Item = &'a PeerId> + 'a {
 		self.peers.iter().filter(|(_, state)| state.is_open()).map(|((id, _), _)| id)
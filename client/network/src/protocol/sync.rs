@@ -688,6 +688,14 @@ impl<B: BlockT> ChainSync<B> {
 
 	/// Get an iterator over all scheduled justification requests.
 	pub fn justification_requests(&mut self) -> impl Iterator<Item = (PeerId, BlockRequest<B>)> + '_ {
+		for who in self.extra_justifications.reschedule_timed_out() {
+			if let Some(peer) = self.peers.get_mut(&who) {
+				if let PeerSyncState::DownloadingJustification(_) = peer.state {
+					peer.state = PeerSyncState::Available;
+				}
+			}
+		}
+
 		let peers = &mut self.peers;
 		let mut matcher = self.extra_justifications.matcher();
 		std::iter::from_fn(move || {
@@ -1983,6 +1991,61 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn timed_out_justification_request_resets_peer_and_ignores_late_answer() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let info = client.info();
+		let peer_id = PeerId::random();
+
+		let mut sync = ChainSync::new(
+			Roles::AUTHORITY,
+			client.clone(),
+			&info,
+			Box::new(DefaultBlockAnnounceValidator),
+			1,
+		);
+
+		let a1 = client.new_block(Default::default()).unwrap().build().unwrap().block;
+		let (a1_hash, a1_number) = (a1.hash(), *a1.header.number());
+
+		sync.new_peer(peer_id.clone(), a1_hash, a1_number).unwrap();
+		sync.request_justification(&a1_hash, a1_number);
+
+		assert!(sync.justification_requests().any(|(who, _)| who == peer_id));
+		assert_eq!(
+			sync.peers.get(&peer_id).unwrap().state,
+			PeerSyncState::DownloadingJustification(a1_hash),
+		);
+
+		// the request is not answered in time
+		sync.extra_justifications.expire_active_requests();
+
+		// the only peer already failed the request, so it isn't sent again right away
+		assert_eq!(sync.justification_requests().count(), 0);
+
+		// but the peer is available again and the request is pending
+		assert_eq!(sync.peers.get(&peer_id).unwrap().state, PeerSyncState::Available);
+		assert_eq!(sync.extra_justifications.active_requests().count(), 0);
+		assert!(
+			sync.extra_justifications.pending_requests().any(|(hash, number)| {
+				*hash == a1_hash && *number == a1_number
+			})
+		);
+
+		// an answer arriving after the timeout is ignored without punishing the peer
+		let mut response = create_block_response(vec![a1]);
+		response.blocks[0].justification = Some(vec![1, 2, 3]);
+		assert_eq!(
+			sync.on_block_justification(peer_id.clone(), response),
+			Ok(OnBlockJustification::Nothing),
+		);
+		assert!(
+			sync.extra_justifications.pending_requests().any(|(hash, number)| {
+				*hash == a1_hash && *number == a1_number
+			})
+		);
+	}
+
 	#[test]
 	fn restart_doesnt_affect_peers_downloading_finality_data() {
 		let mut client = Arc::new(TestClientBuilder::new().build());
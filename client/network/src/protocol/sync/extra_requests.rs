@@ -29,6 +29,12 @@ use wasm_timer::Instant;
 // Time to wait before trying to get the same extra data from the same peer.
 const EXTRA_RETRY_WAIT: Duration = Duration::from_secs(10);
 
+// Time after which an unanswered request is considered failed and re-requested from another peer.
+//
+// This has to be longer than the timeout of the block request protocol, so that answers which are
+// still valid at the transport layer are never discarded.
+const EXTRA_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Pending extra data request for the given block (hash and number).
 pub(crate) type ExtraRequest<B> = (<B as BlockT>::Hash, NumberFor<B>);
 
@@ -45,8 +51,8 @@ pub(crate) struct ExtraRequests<B: BlockT> {
 	best_seen_finalized_number: NumberFor<B>,
 	/// requests which have been queued for later processing
 	pending_requests: VecDeque<ExtraRequest<B>>,
-	/// requests which are currently underway to some peer
-	active_requests: HashMap<PeerId, ExtraRequest<B>>,
+	/// requests which are currently underway to some peer, and when they were sent
+	active_requests: HashMap<PeerId, (ExtraRequest<B>, Instant)>,
 	/// previous requests without response
 	failed_requests: HashMap<ExtraRequest<B>, Vec<(PeerId, Instant)>>,
 	/// successful requests
@@ -114,7 +120,7 @@ impl<B: BlockT> ExtraRequests<B> {
 
 	/// Retry any pending request if a peer disconnected.
 	pub(crate) fn peer_disconnected(&mut self, who: &PeerId) {
-		if let Some(request) = self.active_requests.remove(who) {
+		if let Some((request, _)) = self.active_requests.remove(who) {
 			self.pending_requests.push_front(request);
 		}
 	}
@@ -124,7 +130,7 @@ impl<B: BlockT> ExtraRequests<B> {
 		// we assume that the request maps to the given response, this is
 		// currently enforced by the outer network protocol before passing on
 		// messages to chain sync.
-		if let Some(request) = self.active_requests.remove(&who) {
+		if let Some((request, _)) = self.active_requests.remove(&who) {
 			if let Some(r) = resp {
 				trace!(target: "sync", "Queuing import of {} from {:?} for {:?}",
 					self.request_type_name,
@@ -190,7 +196,7 @@ impl<B: BlockT> ExtraRequests<B> {
 		let roots = self.tree.roots().collect::<HashSet<_>>();
 
 		self.pending_requests.retain(|(h, n)| roots.contains(&(h, n, &())));
-		self.active_requests.retain(|_, (h, n)| roots.contains(&(h, n, &())));
+		self.active_requests.retain(|_, ((h, n), _)| roots.contains(&(h, n, &())));
 		self.failed_requests.retain(|(h, n), _| roots.contains(&(h, n, &())));
 
 		Ok(())
@@ -238,10 +244,47 @@ impl<B: BlockT> ExtraRequests<B> {
 		true
 	}
 
+	/// Reschedule active requests which have not been answered in time.
+	///
+	/// The peer is remembered as having failed the request, so that the request is sent
+	/// to a different peer first. Returns the peers whose requests timed out, so that their
+	/// state can be reset by the caller.
+	pub(crate) fn reschedule_timed_out(&mut self) -> Vec<PeerId> {
+		let timed_out = self.active_requests.iter()
+			.filter(|(_, (_, sent_at))| sent_at.elapsed() >= EXTRA_REQUEST_TIMEOUT)
+			.map(|(peer, _)| peer.clone())
+			.collect::<Vec<_>>();
+
+		for peer in &timed_out {
+			if let Some((request, _)) = self.active_requests.remove(peer) {
+				debug!(target: "sync", "{} request to {:?} for {:?} timed out",
+					self.request_type_name,
+					peer,
+					request,
+				);
+				self.failed_requests.entry(request).or_default().push((peer.clone(), Instant::now()));
+				self.pending_requests.push_front(request);
+			}
+		}
+
+		timed_out
+	}
+
+	/// Make all active requests look as if they were sent before the request timeout.
+	#[cfg(test)]
+	pub(crate) fn expire_active_requests(&mut self) {
+		let sent_at = Instant::now()
+			.checked_sub(EXTRA_REQUEST_TIMEOUT)
+			.expect("monotonic clock is past the request timeout");
+		for (_, request_sent_at) in self.active_requests.values_mut() {
+			*request_sent_at = sent_at;
+		}
+	}
+
 	/// Returns an iterator over all active (in-flight) requests and associated peer id.
 	#[cfg(test)]
 	pub(crate) fn active_requests(&self) -> impl Iterator<Item = (&PeerId, &ExtraRequest<B>)> {
-		self.active_requests.iter()
+		self.active_requests.iter().map(|(peer, (request, _))| (peer, request))
 	}
 
 	/// Returns an iterator over all scheduled pending requests.
@@ -318,7 +361,7 @@ impl<'a, B: BlockT> Matcher<'a, B> {
 				if self.extras.failed_requests.get(&request).map(|rr| rr.iter().any(|i| &i.0 == peer)).unwrap_or(false) {
 					continue
 				}
-				self.extras.active_requests.insert(peer.clone(), request);
+				self.extras.active_requests.insert(peer.clone(), (request, Instant::now()));
 
 				trace!(target: "sync", "Sending {} request to {:?} for {:?}",
 					self.extras.request_type_name,
@@ -413,7 +456,9 @@ mod tests {
 			assert!(requests.pending_requests.is_empty());
 
 			let active_peers = requests.active_requests.keys().cloned().collect::<Vec<_>>();
-			let previously_active = requests.active_requests.values().cloned().collect::<HashSet<_>>();
+			let previously_active = requests.active_requests.values()
+				.map(|(r, _)| *r)
+				.collect::<HashSet<_>>();
 
 			for peer in &active_peers {
 				requests.peer_disconnected(peer)
@@ -445,7 +490,9 @@ mod tests {
 				peers.0.get_mut(&peer).unwrap().state = PeerSyncState::DownloadingJustification(r.0);
 			}
 
-			let active = requests.active_requests.iter().map(|(p, &r)| (p.clone(), r)).collect::<Vec<_>>();
+			let active = requests.active_requests.iter()
+				.map(|(p, &(r, _))| (p.clone(), r))
+				.collect::<Vec<_>>();
 
 			for (peer, req) in &active {
 				assert!(requests.failed_requests.get(req).is_none());
@@ -460,6 +507,24 @@ mod tests {
 			.quickcheck(property as fn(ArbitraryPeers))
 	}
 
+	#[test]
+	fn timed_out_request_is_rescheduled() {
+		let mut requests = ExtraRequests::<Block>::new("test");
+		let peer = PeerId::random();
+		let request = (Hash::random(), 1);
+
+		let sent_at = Instant::now()
+			.checked_sub(EXTRA_REQUEST_TIMEOUT)
+			.expect("monotonic clock is past the request timeout");
+		requests.active_requests.insert(peer.clone(), (request, sent_at));
+
+		assert_eq!(requests.reschedule_timed_out(), vec![peer.clone()]);
+
+		assert!(requests.active_requests.is_empty());
+		assert!(requests.pending_requests.contains(&request));
+		assert_eq!(1, requests.failed_requests.get(&request).unwrap().iter().filter(|(p, _)| p == &peer).count());
+	}
+
 	#[test]
 	fn request_is_rescheduled_when_earlier_block_is_finalized() {
 		sp_tracing::try_init_simple();
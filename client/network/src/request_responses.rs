@@ -65,6 +65,13 @@ pub struct ProtocolConfig {
     /// Name of the protocol on the wire. Should be something like `/foo/bar`.
     pub name: Cow<'static, str>,
 
+    /// Fallback on the wire protocol names to support.
+    ///
+    /// Incoming requests on any of these names are handled exactly like requests on
+    /// [`ProtocolConfig::name`], which allows renaming a protocol without breaking compatibility
+    /// with older nodes.
+    pub fallback_names: Vec<Cow<'static, str>>,
+
     /// Maximum allowed size, in bytes, of a request.
     ///
     /// Any request larger than this value will be declined as a way to avoid allocating too
@@ -266,10 +273,15 @@ impl RequestResponsesBehaviour {
 				ProtocolSupport::Outbound
 			};
 
+			let protocol_names = iter::once(&protocol.name)
+				.chain(protocol.fallback_names.iter())
+				.map(|name| (name.as_bytes().to_vec(), protocol_support.clone()))
+				.collect::<Vec<_>>();
+
 			let rq_rp = RequestResponse::new(GenericCodec {
 				max_request_size: protocol.max_request_size,
 				max_response_size: protocol.max_response_size,
-			}, iter::once((protocol.name.as_bytes().to_vec(), protocol_support)), cfg);
+			}, protocol_names, cfg);
 
 			match protocols.entry(protocol.name) {
 				Entry::Vacant(e) => e.insert((rq_rp, protocol.inbound_queue)),
@@ -923,6 +935,7 @@ mod tests {
 
 				let protocol_config = ProtocolConfig {
 					name: From::from(protocol_name),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 1024 * 1024,
 					request_timeout: Duration::from_secs(30),
@@ -1010,6 +1023,7 @@ mod tests {
 
 				let protocol_config = ProtocolConfig {
 					name: From::from(protocol_name),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 8,  // <-- important for the test
 					request_timeout: Duration::from_secs(30),
@@ -1100,6 +1114,7 @@ mod tests {
 			let protocol_configs = vec![
 				ProtocolConfig {
 					name: From::from(protocol_name_1),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 1024 * 1024,
 					request_timeout: Duration::from_secs(30),
@@ -1107,6 +1122,7 @@ mod tests {
 				},
 				ProtocolConfig {
 					name: From::from(protocol_name_2),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 1024 * 1024,
 					request_timeout: Duration::from_secs(30),
@@ -1124,6 +1140,7 @@ mod tests {
 			let protocol_configs = vec![
 				ProtocolConfig {
 					name: From::from(protocol_name_1),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 1024 * 1024,
 					request_timeout: Duration::from_secs(30),
@@ -1131,6 +1148,7 @@ mod tests {
 				},
 				ProtocolConfig {
 					name: From::from(protocol_name_2),
+					fallback_names: Vec::new(),
 					max_request_size: 1024,
 					max_response_size: 1024 * 1024,
 					request_timeout: Duration::from_secs(30),
@@ -1231,4 +1249,94 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn fallback_protocol_name_works() {
+		let old_protocol_name = "/test/req-resp/1";
+		let new_protocol_name = "/test/req-resp/2";
+		let mut pool = LocalPool::new();
+
+		// Only knows about the old protocol name.
+		let (mut swarm_1, _) = {
+			let protocol_config = ProtocolConfig {
+				name: From::from(old_protocol_name),
+				fallback_names: Vec::new(),
+				max_request_size: 1024,
+				max_response_size: 1024 * 1024,
+				request_timeout: Duration::from_secs(30),
+				inbound_queue: None,
+			};
+
+			build_swarm(iter::once(protocol_config))
+		};
+
+		// Registers the new protocol name and keeps serving the old one as a fallback.
+		let (mut swarm_2, listen_add_2) = {
+			let (tx, mut rx) = mpsc::channel::<IncomingRequest>(64);
+
+			pool.spawner().spawn_obj(async move {
+				while let Some(rq) = rx.next().await {
+					assert_eq!(rq.payload, b"this is a request");
+					let _ = rq.pending_response.send(super::OutgoingResponse {
+						result: Ok(b"this is a response".to_vec()),
+						reputation_changes: Vec::new(),
+					});
+				}
+			}.boxed().into()).unwrap();
+
+			let protocol_config = ProtocolConfig {
+				name: From::from(new_protocol_name),
+				fallback_names: vec![From::from(old_protocol_name)],
+				max_request_size: 1024,
+				max_response_size: 1024 * 1024,
+				request_timeout: Duration::from_secs(30),
+				inbound_queue: Some(tx),
+			};
+
+			build_swarm(iter::once(protocol_config))
+		};
+
+		Swarm::dial_addr(&mut swarm_1, listen_add_2).unwrap();
+
+		pool.spawner().spawn_obj(async move {
+			loop {
+				match swarm_2.next_event().await {
+					SwarmEvent::Behaviour(Event::InboundRequest { result, protocol, .. }) => {
+						// Requests on a fallback name are reported under the main name.
+						assert_eq!(protocol, new_protocol_name);
+						result.unwrap();
+					},
+					_ => {}
+				}
+			}
+		}.boxed().into()).unwrap();
+
+		pool.run_until(async move {
+			let mut response_receiver = None;
+
+			loop {
+				match swarm_1.next_event().await {
+					SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+						let (sender, receiver) = oneshot::channel();
+						swarm_1.send_request(
+							&peer_id,
+							old_protocol_name,
+							b"this is a request".to_vec(),
+							sender,
+							IfDisconnected::ImmediateError,
+						);
+						assert!(response_receiver.is_none());
+						response_receiver = Some(receiver);
+					}
+					SwarmEvent::Behaviour(Event::RequestFinished { result, .. }) => {
+						result.unwrap();
+						break;
+					}
+					_ => {}
+				}
+			}
+
+			assert_eq!(response_receiver.unwrap().await.unwrap().unwrap(), b"this is a response");
+		});
+	}
 }
@@ -262,6 +262,8 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 					&params.protocol_id,
 					checker,
 					peerset_handle.clone(),
+				).with_max_parallel_requests(
+					params.network_config.max_parallel_light_client_requests as usize,
 				)
 			};
 
@@ -595,6 +597,8 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 			connected_peers,
 			not_connected_peers,
 			peerset: swarm.user_protocol_mut().peerset_debug_info(),
+			total_bytes_inbound: self.service.bandwidth.total_inbound(),
+			total_bytes_outbound: self.service.bandwidth.total_outbound(),
 		}
 	}
 
@@ -611,6 +615,11 @@ impl<B: BlockT + 'static, H: ExHashT> NetworkWorker<B, H> {
 		self.service.remove_reserved_peer(peer);
 	}
 
+	/// Returns the list of reserved peers.
+	pub fn reserved_peers(&self) -> impl Iterator<Item = &PeerId> {
+		self.network_service.user_protocol().reserved_peers()
+	}
+
 	/// Adds a `PeerId` and its address as reserved. The string should encode the address
 	/// and peer ID of the remote node.
 	pub fn add_reserved_peer(&self, peer: String) -> Result<(), String> {
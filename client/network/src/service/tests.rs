@@ -31,6 +31,11 @@ type TestNetworkService = NetworkService<
 	substrate_test_runtime_client::runtime::Hash,
 >;
 
+type TestNetworkWorker = NetworkWorker<
+	substrate_test_runtime_client::runtime::Block,
+	substrate_test_runtime_client::runtime::Hash,
+>;
+
 /// Builds a full node to be used for testing. Returns the node service and its associated events
 /// stream.
 ///
@@ -38,6 +43,22 @@ type TestNetworkService = NetworkService<
 /// >			construction of the service and the moment the events stream is grabbed.
 fn build_test_full_node(config: config::NetworkConfiguration)
 	-> (Arc<TestNetworkService>, impl Stream<Item = Event>)
+{
+	let (worker, event_stream) = build_test_full_node_worker(config);
+	let service = worker.service().clone();
+
+	async_std::task::spawn(async move {
+		futures::pin_mut!(worker);
+		let _ = worker.await;
+	});
+
+	(service, event_stream)
+}
+
+/// Builds a full node to be used for testing, like `build_test_full_node`, but leaves polling
+/// the worker to the caller.
+fn build_test_full_node_worker(config: config::NetworkConfiguration)
+	-> (TestNetworkWorker, impl Stream<Item = Event>)
 {
 	let client = Arc::new(
 		TestClientBuilder::with_default_backend()
@@ -131,15 +152,9 @@ fn build_test_full_node(config: config::NetworkConfiguration)
 	})
 	.unwrap();
 
-	let service = worker.service().clone();
-	let event_stream = service.event_stream("test");
+	let event_stream = worker.service().event_stream("test");
 
-	async_std::task::spawn(async move {
-		futures::pin_mut!(worker);
-		let _ = worker.await;
-	});
-
-	(service, event_stream)
+	(worker, event_stream)
 }
 
 const PROTOCOL_NAME: Cow<'static, str> = Cow::Borrowed("/foo");
@@ -452,6 +467,66 @@ fn notifications_back_pressure() {
 	});
 }
 
+#[test]
+fn network_state_reports_bandwidth_totals() {
+	let listen_addr = config::build_multiaddr![Memory(rand::random::<u64>())];
+
+	let (mut worker1, mut events_stream1) = build_test_full_node_worker(
+		config::NetworkConfiguration {
+			extra_sets: vec![
+				config::NonDefaultSetConfig {
+					notifications_protocol: PROTOCOL_NAME,
+					max_notification_size: 1024 * 1024,
+					set_config: Default::default()
+				}
+			],
+			listen_addresses: vec![listen_addr.clone()],
+			transport: config::TransportConfig::MemoryOnly,
+			.. config::NetworkConfiguration::new_local()
+		}
+	);
+
+	let state = worker1.network_state();
+	assert_eq!(state.total_bytes_inbound, 0);
+	assert_eq!(state.total_bytes_outbound, 0);
+
+	let (_node2, _events_stream2) = build_test_full_node(config::NetworkConfiguration {
+		extra_sets: vec![
+			config::NonDefaultSetConfig {
+				notifications_protocol: PROTOCOL_NAME,
+				max_notification_size: 1024 * 1024,
+				set_config: config::SetConfig {
+					reserved_nodes: vec![config::MultiaddrWithPeerId {
+						multiaddr: listen_addr,
+						peer_id: worker1.local_peer_id().clone(),
+					}],
+					.. Default::default()
+				}
+			}
+		],
+		listen_addresses: vec![],
+		transport: config::TransportConfig::MemoryOnly,
+		.. config::NetworkConfiguration::new_local()
+	});
+
+	async_std::task::block_on(async {
+		// Drive the first node until the notifications substream with the second one is open,
+		// which requires traffic in both directions.
+		loop {
+			match future::select(&mut worker1, events_stream1.next()).await {
+				future::Either::Left(_) => panic!("network worker terminated"),
+				future::Either::Right((Some(Event::NotificationStreamOpened { .. }), _)) => break,
+				future::Either::Right((Some(_), _)) => {},
+				future::Either::Right((None, _)) => panic!("event stream terminated"),
+			}
+		}
+	});
+
+	let state = worker1.network_state();
+	assert!(state.total_bytes_inbound > 0);
+	assert!(state.total_bytes_outbound > 0);
+}
+
 #[test]
 #[should_panic(expected = "don't match the transport")]
 fn ensure_listen_addresses_consistent_with_transport_memory() {
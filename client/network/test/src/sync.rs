@@ -935,3 +935,40 @@ fn continue_to_sync_after_some_block_announcement_verifications_failed() {
 	net.block_until_sync();
 	assert!(net.peer(1).has_block(&block_hash));
 }
+
+/// Ensures that two peers only connected through an intermediate peer exchange their competing
+/// forks through it and agree on the longest one.
+#[test]
+fn reorgs_to_longest_fork_through_intermediate_peer() {
+	sp_tracing::try_init_simple();
+	let mut net = TestNet::new(2);
+
+	net.add_full_peer_with_config(FullPeerConfig {
+		connect_to_peers: Some(vec![1]),
+		..Default::default()
+	});
+
+	// Wait until peer 1 is connected to both nodes.
+	block_on(futures::future::poll_fn::<(), _>(|cx| {
+		net.poll(cx);
+		if net.peer(1).num_peers() == 2 {
+			Poll::Ready(())
+		} else {
+			Poll::Pending
+		}
+	}));
+
+	// Peers 0 and 2 author competing forks from genesis.
+	let longest_hash = net.peer(0).push_blocks(10, false);
+	let shorter_hash = net.peer(2).push_blocks(5, true);
+	assert_eq!(net.peer(2).client().info().best_hash, shorter_hash);
+
+	while !net.peer(1).has_block(&shorter_hash) || !net.peer(2).has_block(&longest_hash) {
+		net.block_until_idle();
+	}
+
+	// Peer 2 reorged to the fork it only learnt about through peer 1.
+	assert_eq!(net.peer(1).client().info().best_hash, longest_hash);
+	assert_eq!(net.peer(2).client().info().best_hash, longest_hash);
+	assert!(net.peers()[0].blockchain_canon_equals(&net.peers()[2]));
+}
@@ -43,8 +43,12 @@ impl MetricsLink {
 /// Authorship metrics.
 #[derive(Clone)]
 pub struct Metrics {
+	/// Time taken to fill a block with transactions from the pool and build it.
 	pub block_constructed: Histogram,
+	/// Number of transactions included in the last constructed block.
 	pub number_of_transactions: Gauge<U64>,
+	/// Number of transactions skipped in the last constructed block because it was already full.
+	pub number_of_skipped_transactions: Gauge<U64>,
 }
 
 impl Metrics {
@@ -64,6 +68,13 @@ impl Metrics {
 				)?,
 				registry,
 			)?,
+			number_of_skipped_transactions: register(
+				Gauge::new(
+					"proposer_number_of_skipped_transactions",
+					"Number of transactions skipped because the block was full",
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
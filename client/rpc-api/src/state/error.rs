@@ -53,6 +53,9 @@ pub enum Error {
 	},
 	/// Call to an unsafe RPC was denied.
 	UnsafeRpcCalled(crate::policy::UnsafeRpcError),
+	/// The state of the requested block has been pruned.
+	#[display(fmt = "State already discarded for {}", _0)]
+	StateAlreadyDiscarded(String),
 }
 
 impl std::error::Error for Error {
@@ -80,6 +83,11 @@ impl From<Error> for rpc::Error {
 				message: format!("{}", e),
 				data: None,
 			},
+			Error::StateAlreadyDiscarded(_) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 3),
+				message: format!("{}", e),
+				data: None,
+			},
 			e => errors::internal(e),
 		}
 	}
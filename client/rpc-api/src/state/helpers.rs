@@ -18,7 +18,7 @@
 
 //! Substrate state API helpers.
 
-use sp_core::Bytes;
+use sp_core::{Bytes, storage::{StorageKey, StorageData}};
 use serde::{Serialize, Deserialize};
 
 /// ReadProof struct returned by the RPC
@@ -30,3 +30,13 @@ pub struct ReadProof<Hash> {
 	/// A proof used to prove that storage entries are included in the storage trie
 	pub proof: Vec<Bytes>,
 }
+
+/// Result of a runtime call executed with storage overrides, returned by the RPC
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallResult {
+	/// SCALE encoded result of the call
+	pub result: Bytes,
+	/// Storage changes made by the call, including the overrides
+	pub changes: Vec<(StorageKey, Option<StorageData>)>,
+}
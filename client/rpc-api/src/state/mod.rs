@@ -30,7 +30,7 @@ use sp_version::RuntimeVersion;
 use self::error::FutureResult;
 
 pub use self::gen_client::Client as StateClient;
-pub use self::helpers::ReadProof;
+pub use self::helpers::{ReadProof, CallResult};
 
 /// Substrate state API
 #[rpc]
@@ -42,6 +42,19 @@ pub trait StateApi<Hash> {
 	#[rpc(name = "state_call", alias("state_callAt"))]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> FutureResult<Bytes>;
 
+	/// Call a contract at a block's state, with the given storage overrides applied on top.
+	///
+	/// Nothing is persisted. Returns the result of the call along with all storage changes
+	/// relative to the block's state, including the overrides.
+	#[rpc(name = "state_callWithOverrides")]
+	fn call_with_overrides(
+		&self,
+		name: String,
+		bytes: Bytes,
+		overrides: Vec<(StorageKey, Option<StorageData>)>,
+		hash: Option<Hash>,
+	) -> FutureResult<CallResult>;
+
 	/// DEPRECATED: Please use `state_getKeysPaged` with proper paging support.
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[rpc(name = "state_getKeys")]
@@ -72,6 +85,10 @@ pub trait StateApi<Hash> {
 	fn storage_hash(&self, key: StorageKey, hash: Option<Hash>) -> FutureResult<Option<Hash>>;
 
 	/// Returns the size of a storage entry at a block's state.
+	///
+	/// If there is no entry under the exact key, the key is treated as a prefix and the sizes of
+	/// all entries under it are summed. Passing `twox_128(pallet_prefix)` thus returns the
+	/// storage used by a whole pallet.
 	#[rpc(name = "state_getStorageSize", alias("state_getStorageSizeAt"))]
 	fn storage_size(&self, key: StorageKey, hash: Option<Hash>) -> FutureResult<Option<u64>>;
 
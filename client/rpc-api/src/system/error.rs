@@ -32,6 +32,9 @@ pub enum Error {
 	NotHealthy(Health),
 	/// Peer argument is malformatted.
 	MalformattedPeerArg(String),
+	/// The best block was imported longer ago than the configured maximum, in seconds.
+	#[display(fmt = "Best block was imported {} seconds ago", _0)]
+	StaleBestBlock(u64),
 }
 
 impl std::error::Error for Error {}
@@ -51,7 +54,12 @@ impl From<Error> for rpc::Error {
 				code :rpc::ErrorCode::ServerError(BASE_ERROR + 2),
 				message: e.clone(),
 				data: None,
-			}
+			},
+			Error::StaleBestBlock(_) => rpc::Error {
+				code: rpc::ErrorCode::ServerError(BASE_ERROR + 3),
+				message: format!("{}", e),
+				data: None,
+			},
 		}
 	}
 }
@@ -61,6 +61,15 @@ pub trait SystemApi<Hash, Number> {
 	#[rpc(name = "system_health", returns = "Health")]
 	fn system_health(&self) -> Receiver<Health>;
 
+	/// Return health status of the node if it is ready to serve requests.
+	///
+	/// Fails with the health status as error data if the node is not healthy as described for
+	/// `system_health`, which allows orchestration tools to use it as a readiness probe.
+	/// If the node is configured with a maximum block age, it also fails once the best block
+	/// was imported longer ago than that. The HTTP server serves it at `/ready`.
+	#[rpc(name = "system_ready", returns = "Health")]
+	fn system_ready(&self) -> Compat<BoxFuture<'static, jsonrpc_core::Result<Health>>>;
+
 	/// Returns the base58-encoded PeerId of the node.
 	#[rpc(name = "system_localPeerId", returns = "String")]
 	fn system_local_peer_id(&self) -> Receiver<String>;
@@ -102,6 +111,10 @@ pub trait SystemApi<Hash, Number> {
 	fn system_remove_reserved_peer(&self, peer_id: String)
 		-> Compat<BoxFuture<'static, Result<(), jsonrpc_core::Error>>>;
 
+	/// Returns the list of reserved peers.
+	#[rpc(name = "system_reservedPeers", returns = "Vec<String>")]
+	fn system_reserved_peers(&self) -> Receiver<Vec<String>>;
+
 	/// Returns the roles the node is running as.
 	#[rpc(name = "system_nodeRoles", returns = "Vec<NodeRole>")]
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>>;
@@ -27,8 +27,10 @@ use jsonrpc_core::{IoHandlerExtension, MetaIoHandler};
 use log::error;
 use pubsub::PubSubMetadata;
 
-/// Maximal payload accepted by RPC servers.
-const MAX_PAYLOAD: usize = 15 * 1024 * 1024;
+const MEGABYTE: usize = 1024 * 1024;
+
+/// Default maximum size of the requests accepted by RPC servers.
+const RPC_MAX_PAYLOAD_DEFAULT: usize = 15 * MEGABYTE;
 
 /// Default maximum number of connections for WS RPC servers.
 const WS_MAX_CONNECTIONS: usize = 100;
@@ -66,6 +68,10 @@ pub fn rpc_handler<M: PubSubMetadata>(
 #[cfg(not(target_os = "unknown"))]
 mod inner {
 	use super::*;
+	use futures::Future;
+
+	/// Path of the HTTP readiness endpoint, answered through `system_ready`.
+	const READY_PATH: &str = "/ready";
 
 	/// Type alias for ipc server
 	pub type IpcServer = ipc::Server;
@@ -76,15 +82,26 @@ mod inner {
 
 	/// Start HTTP server listening on given address.
 	///
+	/// Besides RPC requests, the server answers `GET /health` through `system_health` and
+	/// `GET /ready` through `system_ready`.
+	///
 	/// **Note**: Only available if `not(target_os = "unknown")`.
 	pub fn start_http<M: pubsub::PubSubMetadata + Default>(
 		addr: &std::net::SocketAddr,
 		cors: Option<&Vec<String>>,
 		io: RpcHandler<M>,
+		maybe_max_payload_mb: Option<usize>,
 	) -> io::Result<http::Server> {
+		let max_request_body_size = maybe_max_payload_mb.map(|mb| mb.saturating_mul(MEGABYTE))
+			.unwrap_or(RPC_MAX_PAYLOAD_DEFAULT);
+
+		let io: MetaIoHandler<M, RpcMiddleware> = io.into();
+		let ready_io = io.clone();
+
 		http::ServerBuilder::new(io)
 			.threads(4)
 			.health_api(("/health", "system_health"))
+			.request_middleware(ready_middleware(ready_io))
 			.allowed_hosts(hosts_filtering(cors.is_some()))
 			.rest_api(if cors.is_some() {
 				http::RestApi::Secure
@@ -92,10 +109,57 @@ mod inner {
 				http::RestApi::Unsecure
 			})
 			.cors(map_cors::<http::AccessControlAllowOrigin>(cors))
-			.max_request_body_size(MAX_PAYLOAD)
+			.max_request_body_size(max_request_body_size)
 			.start_http(addr)
 	}
 
+	/// Answer `GET` requests to [`READY_PATH`] the way the health endpoint is answered, but through
+	/// `system_ready`. Its result is returned with a 200 status and its error with a 503 status.
+	fn ready_middleware<M: pubsub::PubSubMetadata + Default>(
+		io: MetaIoHandler<M, RpcMiddleware>,
+	) -> impl http::RequestMiddleware {
+		use http::hyper::{self, header, Body, Method, StatusCode};
+		use jsonrpc_core::{Call, Id, MethodCall, Output, Params, Request, Response, Version};
+
+		move |request: hyper::Request<Body>| {
+			if request.method() != Method::GET || request.uri().path() != READY_PATH {
+				return http::RequestMiddlewareAction::Proceed {
+					should_continue_on_invalid_cors: false,
+					request,
+				}
+			}
+
+			let call = Request::Single(Call::MethodCall(MethodCall {
+				jsonrpc: Some(Version::V2),
+				method: "system_ready".into(),
+				params: Params::None,
+				id: Id::Num(1),
+			}));
+			let response = io.handle_rpc_request(call, M::default()).then(|response| {
+				let (status, body) = match response {
+					Ok(Some(Response::Single(Output::Success(success)))) =>
+						(StatusCode::OK, serde_json::to_string(&success.result)),
+					Ok(Some(Response::Single(Output::Failure(failure)))) =>
+						(StatusCode::SERVICE_UNAVAILABLE, serde_json::to_string(&failure.error)),
+					_ => (StatusCode::INTERNAL_SERVER_ERROR, Ok(String::new())),
+				};
+
+				let mut response = hyper::Response::new(Body::from(body.unwrap_or_default()));
+				*response.status_mut() = status;
+				response.headers_mut().insert(
+					header::CONTENT_TYPE,
+					header::HeaderValue::from_static("application/json"),
+				);
+				Ok::<_, hyper::Error>(response)
+			});
+
+			http::RequestMiddlewareAction::Respond {
+				should_validate_hosts: true,
+				response: Box::new(response),
+			}
+		}
+	}
+
 	/// Start IPC server listening on given path.
 	///
 	/// **Note**: Only available if `not(target_os = "unknown")`.
@@ -104,12 +168,11 @@ mod inner {
 		io: RpcHandler<M>,
 	) -> io::Result<ipc::Server> {
 		let builder = ipc::ServerBuilder::new(io);
-		#[cfg(target_os = "unix")]
-		builder.set_security_attributes({
-			let security_attributes = ipc::SecurityAttributes::empty();
-			security_attributes.set_mode(0o600)?;
-			security_attributes
-		});
+		// only the user running the node may connect to the socket.
+		#[cfg(unix)]
+		let builder = builder.set_security_attributes(
+			ipc::SecurityAttributes::empty().set_mode(0o600)?
+		);
 		builder.start(addr)
 	}
 
@@ -121,9 +184,13 @@ mod inner {
 		max_connections: Option<usize>,
 		cors: Option<&Vec<String>>,
 		io: RpcHandler<M>,
+		maybe_max_payload_mb: Option<usize>,
 	) -> io::Result<ws::Server> {
+		let rpc_max_payload = maybe_max_payload_mb.map(|mb| mb.saturating_mul(MEGABYTE))
+			.unwrap_or(RPC_MAX_PAYLOAD_DEFAULT);
+
 		ws::ServerBuilder::with_meta_extractor(io, |context: &ws::RequestContext| context.sender().into())
-			.max_payload(MAX_PAYLOAD)
+			.max_payload(rpc_max_payload)
 			.max_connections(max_connections.unwrap_or(WS_MAX_CONNECTIONS))
 			.allowed_origins(map_cors(cors))
 			.allowed_hosts(hosts_filtering(cors.is_some()))
@@ -159,3 +226,90 @@ mod inner {
 #[cfg(target_os = "unknown")]
 mod inner {
 }
+
+#[cfg(all(test, not(target_os = "unknown")))]
+mod tests {
+	use super::*;
+	use std::{io::{Read, Write}, net::{SocketAddr, TcpStream}, sync::Arc};
+
+	#[derive(Default, Clone)]
+	struct TestMetadata;
+
+	impl jsonrpc_core::Metadata for TestMetadata {}
+
+	impl PubSubMetadata for TestMetadata {
+		fn session(&self) -> Option<Arc<pubsub::Session>> {
+			None
+		}
+	}
+
+	fn http_post(addr: &SocketAddr, body: &[u8]) -> String {
+		let mut stream = TcpStream::connect(addr).expect("connects to the server");
+		write!(
+			stream,
+			"POST / HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+			Content-Length: {}\r\nConnection: close\r\n\r\n",
+			addr,
+			body.len(),
+		).expect("writes the request head");
+		stream.write_all(body).expect("writes the request body");
+
+		let mut response = String::new();
+		stream.read_to_string(&mut response).expect("reads the response");
+		response
+	}
+
+	fn http_get(addr: &SocketAddr, path: &str) -> String {
+		let mut stream = TcpStream::connect(addr).expect("connects to the server");
+		write!(
+			stream,
+			"GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+			path,
+			addr,
+		).expect("writes the request head");
+
+		let mut response = String::new();
+		stream.read_to_string(&mut response).expect("reads the response");
+		response
+	}
+
+	#[test]
+	fn http_server_rejects_requests_above_max_payload() {
+		let io = rpc_handler(
+			MetaIoHandler::<TestMetadata>::default(),
+			RpcMiddleware::new(RpcMetrics::new(None).unwrap(), "http"),
+		);
+		let server = start_http(&"127.0.0.1:0".parse().unwrap(), None, io, Some(1))
+			.expect("starts the http server");
+		let addr = *server.address();
+
+		let request = br#"{"jsonrpc":"2.0","method":"rpc_methods","params":[],"id":1}"#;
+		assert!(http_post(&addr, request).starts_with("HTTP/1.1 200"));
+
+		let oversized = vec![b' '; MEGABYTE + 1];
+		assert!(http_post(&addr, &oversized).starts_with("HTTP/1.1 413"));
+
+		server.close();
+	}
+
+	#[test]
+	fn http_server_serves_health_and_readiness() {
+		let mut methods = MetaIoHandler::<TestMetadata>::default();
+		methods.add_method("system_health", |_| Ok(serde_json::json!({ "peers": 0 })));
+		methods.add_method("system_ready", |_| {
+			Err::<serde_json::Value, _>(jsonrpc_core::Error::internal_error())
+		});
+		let io = rpc_handler(
+			methods,
+			RpcMiddleware::new(RpcMetrics::new(None).unwrap(), "http"),
+		);
+		let server = start_http(&"127.0.0.1:0".parse().unwrap(), None, io, None)
+			.expect("starts the http server");
+		let addr = *server.address();
+
+		assert!(http_get(&addr, "/health").starts_with("HTTP/1.1 200"));
+		assert!(http_get(&addr, "/ready").starts_with("HTTP/1.1 503"));
+
+		server.close();
+	}
+}
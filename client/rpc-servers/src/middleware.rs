@@ -56,6 +56,7 @@ impl RpcMetrics {
 }
 
 /// Middleware for RPC calls
+#[derive(Clone)]
 pub struct RpcMiddleware {
 	metrics: RpcMetrics,
 	transport_label: String,
@@ -224,6 +224,22 @@ fn should_remove_extrinsics() {
 	assert_eq!(removed.len(), 3);
 }
 
+#[test]
+fn should_not_remove_extrinsics_when_unsafe_is_denied() {
+	let setup = TestSetup::default();
+	let p = Author {
+		deny_unsafe: DenyUnsafe::Yes,
+		..setup.author()
+	};
+
+	let ex = uxt(AccountKeyring::Alice, 0);
+	let hash = p.submit_extrinsic(ex.encode().into()).wait().unwrap();
+	assert_eq!(setup.pool.status().ready, 1);
+
+	assert!(p.remove_extrinsic(vec![hash::ExtrinsicOrHash::Hash(hash)]).is_err());
+	assert_eq!(setup.pool.status().ready, 1);
+}
+
 #[test]
 fn should_insert_key() {
 	let setup = TestSetup::default();
@@ -59,6 +59,15 @@ pub trait StateBackend<Block: BlockT, Client>: Send + Sync + 'static
 		call_data: Bytes,
 	) -> FutureResult<Bytes>;
 
+	/// Call runtime method at given block with the given storage overrides applied.
+	fn call_with_overrides(
+		&self,
+		block: Option<Block::Hash>,
+		method: String,
+		call_data: Bytes,
+		overrides: Vec<(StorageKey, Option<StorageData>)>,
+	) -> FutureResult<CallResult>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	fn storage_keys(
 		&self,
@@ -240,6 +249,20 @@ impl<Block, Client> StateApi<Block::Hash> for State<Block, Client>
 		self.backend.call(block, method, data)
 	}
 
+	fn call_with_overrides(
+		&self,
+		method: String,
+		data: Bytes,
+		overrides: Vec<(StorageKey, Option<StorageData>)>,
+		block: Option<Block::Hash>,
+	) -> FutureResult<CallResult> {
+		if let Err(err) = self.deny_unsafe.check_if_safe() {
+			return Box::new(result(Err(err.into())));
+		}
+
+		self.backend.call_with_overrides(block, method, data, overrides)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
@@ -442,5 +465,8 @@ impl<Block, Client> ChildStateApi<Block::Hash> for ChildState<Block, Client>
 }
 
 fn client_err(err: sp_blockchain::Error) -> Error {
-	Error::Client(Box::new(err))
+	match err {
+		sp_blockchain::Error::StateAlreadyDiscarded(block) => Error::StateAlreadyDiscarded(block),
+		err => Error::Client(Box::new(err)),
+	}
 }
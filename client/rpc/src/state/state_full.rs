@@ -26,7 +26,7 @@ use log::warn;
 use jsonrpc_pubsub::{typed::Subscriber, SubscriptionId, manager::SubscriptionManager};
 use rpc::{Result as RpcResult, futures::{stream, Future, Sink, Stream, future::result}};
 
-use sc_rpc_api::state::ReadProof;
+use sc_rpc_api::state::{ReadProof, CallResult};
 use sc_client_api::backend::Backend;
 use sp_blockchain::{Result as ClientResult, Error as ClientError, HeaderMetadata, CachedHeaderMetadata, HeaderBackend};
 use sc_client_api::BlockchainEvents;
@@ -249,6 +249,38 @@ impl<BE, Block, Client> StateBackend<Block, Client> for FullState<BE, Block, Cli
 		Box::new(result(r))
 	}
 
+	fn call_with_overrides(
+		&self,
+		block: Option<Block::Hash>,
+		method: String,
+		call_data: Bytes,
+		overrides: Vec<(StorageKey, Option<StorageData>)>,
+	) -> FutureResult<CallResult> {
+		let overrides = overrides.into_iter()
+			.map(|(key, value)| (key.0, value.map(|value| value.0)))
+			.collect();
+		let r = self.block_or_best(block)
+			.and_then(|block| self
+				.client
+				.executor()
+				.call_with_overrides(
+					&BlockId::Hash(block),
+					&method,
+					&*call_data,
+					overrides,
+					self.client.execution_extensions().strategies().other,
+					None,
+				)
+				.map(|(data, changes)| CallResult {
+					result: data.into(),
+					changes: changes.into_iter()
+						.map(|(key, value)| (StorageKey(key), value.map(StorageData)))
+						.collect(),
+				})
+			).map_err(client_err);
+		Box::new(result(r))
+	}
+
 	fn storage_keys(
 		&self,
 		block: Option<Block::Hash>,
@@ -40,7 +40,7 @@ use rpc::{
 	futures::stream::Stream,
 };
 
-use sc_rpc_api::state::ReadProof;
+use sc_rpc_api::state::{ReadProof, CallResult};
 use sp_blockchain::{Error as ClientError, HeaderBackend};
 use sc_client_api::{
 	BlockchainEvents,
@@ -190,6 +190,16 @@ impl<Block, F, Client> StateBackend<Block, Client> for LightState<Block, F, Clie
 		).boxed().compat())
 	}
 
+	fn call_with_overrides(
+		&self,
+		_block: Option<Block::Hash>,
+		_method: String,
+		_call_data: Bytes,
+		_overrides: Vec<(StorageKey, Option<StorageData>)>,
+	) -> FutureResult<CallResult> {
+		Box::new(result(Err(client_err(ClientError::NotAvailableOnLightClient))))
+	}
+
 	fn storage_keys(
 		&self,
 		_block: Option<Block::Hash>,
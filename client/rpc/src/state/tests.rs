@@ -27,6 +27,7 @@ use sp_core::{storage::ChildInfo, ChangesTrieConfiguration};
 use sp_core::hash::H256;
 use sc_block_builder::BlockBuilderProvider;
 use sp_io::hashing::blake2_256;
+use codec::Encode;
 use substrate_test_runtime_client::{
 	prelude::*,
 	sp_consensus::BlockOrigin,
@@ -152,6 +153,63 @@ fn should_call_contract() {
 	)
 }
 
+#[test]
+fn should_call_contract_with_storage_overrides() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (api, _child) = new_full(
+		client.clone(),
+		SubscriptionManager::new(Arc::new(TaskExecutor)),
+		DenyUnsafe::No,
+	);
+
+	let ferdie: runtime::AccountId = AccountKeyring::Ferdie.into();
+	let ferdie_balance_key = StorageKey(
+		blake2_256(&runtime::system::balance_of_key(ferdie.clone())).to_vec(),
+	);
+	let overrides = vec![(ferdie_balance_key.clone(), Some(StorageData(1234u64.encode())))];
+
+	let call_result = api.call_with_overrides(
+		"TestAPI_balance_of".into(),
+		Bytes(ferdie.encode()),
+		overrides,
+		Some(genesis_hash),
+	).wait().unwrap();
+
+	assert_eq!(call_result.result, Bytes(1234u64.encode()));
+	assert_eq!(
+		call_result.changes,
+		vec![(ferdie_balance_key.clone(), Some(StorageData(1234u64.encode())))],
+	);
+
+	// nothing is persisted
+	assert_eq!(
+		client.storage(&BlockId::Hash(genesis_hash), &ferdie_balance_key).unwrap(),
+		None,
+	);
+}
+
+#[test]
+fn should_deny_calls_with_storage_overrides_when_unsafe_is_denied() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let genesis_hash = client.genesis_hash();
+	let (api, _child) = new_full(
+		client,
+		SubscriptionManager::new(Arc::new(TaskExecutor)),
+		DenyUnsafe::Yes,
+	);
+
+	assert_matches!(
+		api.call_with_overrides(
+			"TestAPI_balance_of".into(),
+			Bytes(vec![]),
+			Vec::new(),
+			Some(genesis_hash),
+		).wait(),
+		Err(Error::UnsafeRpcCalled(_))
+	);
+}
+
 #[test]
 fn should_notify_about_storage_changes() {
 	let (subscriber, id, transport) = Subscriber::new_test("test");
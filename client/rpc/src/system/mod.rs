@@ -27,6 +27,7 @@ use sc_rpc_api::{DenyUnsafe, Receiver};
 use sc_tracing::logging;
 use sp_utils::mpsc::TracingUnboundedSender;
 use sp_runtime::traits::{self, Header as HeaderT};
+use std::time::Duration;
 
 use self::error::Result;
 
@@ -47,12 +48,16 @@ pub struct System<B: traits::Block> {
 	info: SystemInfo,
 	send_back: TracingUnboundedSender<Request<B>>,
 	deny_unsafe: DenyUnsafe,
+	max_block_age: Option<Duration>,
 }
 
 /// Request to be processed.
 pub enum Request<B: traits::Block> {
 	/// Must return the health of the network.
 	Health(oneshot::Sender<Health>),
+	/// Must return the time elapsed since the last new best block was imported, or since the
+	/// node started if none was.
+	BestBlockAge(oneshot::Sender<Duration>),
 	/// Must return the base58-encoded local `PeerId`.
 	LocalPeerId(oneshot::Sender<String>),
 	/// Must return the string representation of the addresses we listen on, including the
@@ -66,6 +71,8 @@ pub enum Request<B: traits::Block> {
 	NetworkAddReservedPeer(String, oneshot::Sender<Result<()>>),
 	/// Must return any potential parse error.
 	NetworkRemoveReservedPeer(String, oneshot::Sender<Result<()>>),
+	/// Must return the list of reserved peers.
+	NetworkReservedPeers(oneshot::Sender<Vec<String>>),
 	/// Must return the node role.
 	NodeRoles(oneshot::Sender<Vec<NodeRole>>),
 	/// Must return the state of the node syncing.
@@ -77,15 +84,20 @@ impl<B: traits::Block> System<B> {
 	///
 	/// The `send_back` will be used to transmit some of the requests. The user is responsible for
 	/// reading from that channel and answering the requests.
+	///
+	/// `system_ready` fails once the best block was imported longer than `max_block_age` ago,
+	/// if given.
 	pub fn new(
 		info: SystemInfo,
 		send_back: TracingUnboundedSender<Request<B>>,
 		deny_unsafe: DenyUnsafe,
+		max_block_age: Option<Duration>,
 	) -> Self {
 		System {
 			info,
 			send_back,
 			deny_unsafe,
+			max_block_age,
 		}
 	}
 }
@@ -117,6 +129,33 @@ impl<B: traits::Block> SystemApi<B::Hash, <B::Header as HeaderT>::Number> for Sy
 		Receiver(Compat::new(rx))
 	}
 
+	fn system_ready(&self) -> Compat<BoxFuture<'static, rpc::Result<Health>>> {
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::Health(tx));
+
+		let best_block_age = self.max_block_age.map(|max_block_age| {
+			let (tx, rx) = oneshot::channel();
+			let _ = self.send_back.unbounded_send(Request::BestBlockAge(tx));
+			(max_block_age, rx)
+		});
+
+		async move {
+			let health = rx.await.map_err(|_| rpc::Error::internal_error())?;
+			if health.is_syncing || (health.should_have_peers && health.peers == 0) {
+				return Err(error::Error::NotHealthy(health).into())
+			}
+
+			if let Some((max_block_age, rx)) = best_block_age {
+				let age = rx.await.map_err(|_| rpc::Error::internal_error())?;
+				if age > max_block_age {
+					return Err(error::Error::StaleBestBlock(age.as_secs()).into())
+				}
+			}
+
+			Ok(health)
+		}.boxed().compat()
+	}
+
 	fn system_local_peer_id(&self) -> Receiver<String> {
 		let (tx, rx) = oneshot::channel();
 		let _ = self.send_back.unbounded_send(Request::LocalPeerId(tx));
@@ -187,6 +226,12 @@ impl<B: traits::Block> SystemApi<B::Hash, <B::Header as HeaderT>::Number> for Sy
 		}.boxed().compat()
 	}
 
+	fn system_reserved_peers(&self) -> Receiver<Vec<String>> {
+		let (tx, rx) = oneshot::channel();
+		let _ = self.send_back.unbounded_send(Request::NetworkReservedPeers(tx));
+		Receiver(Compat::new(rx))
+	}
+
 	fn system_node_roles(&self) -> Receiver<Vec<NodeRole>> {
 		let (tx, rx) = oneshot::channel();
 		let _ = self.send_back.unbounded_send(Request::NodeRoles(tx));
@@ -202,11 +247,20 @@ impl<B: traits::Block> SystemApi<B::Hash, <B::Header as HeaderT>::Number> for Sy
 	fn system_add_log_filter(&self, directives: String) -> std::result::Result<(), rpc::Error> {
 		self.deny_unsafe.check_if_safe()?;
 		logging::add_directives(&directives);
-		logging::reload_filter().map_err(|_e| rpc::Error::internal_error())
+		logging::reload_filter().map_err(log_filter_error)
 	}
 
 	fn system_reset_log_filter(&self)-> std::result::Result<(), rpc::Error> {
 		self.deny_unsafe.check_if_safe()?;
-		logging::reset_log_filter().map_err(|_e| rpc::Error::internal_error())
+		logging::reset_log_filter().map_err(log_filter_error)
+	}
+}
+
+/// Reports why the log filter couldn't be reloaded, e.g. because log reloading is disabled.
+fn log_filter_error(message: String) -> rpc::Error {
+	rpc::Error {
+		code: rpc::ErrorCode::InternalError,
+		message,
+		data: None,
 	}
 }
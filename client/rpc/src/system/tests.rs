@@ -34,6 +34,7 @@ struct Status {
 	pub is_syncing: bool,
 	pub is_dev: bool,
 	pub peer_id: PeerId,
+	pub best_block_age: Duration,
 }
 
 impl Default for Status {
@@ -43,11 +44,19 @@ impl Default for Status {
 			peers: 0,
 			is_syncing: false,
 			is_dev: false,
+			best_block_age: Duration::from_secs(0),
 		}
 	}
 }
 
 fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
+	api_with_max_block_age(sync, None)
+}
+
+fn api_with_max_block_age<T: Into<Option<Status>>>(
+	sync: T,
+	max_block_age: Option<Duration>,
+) -> System<Block> {
 	let status = sync.into().unwrap_or_default();
 	let should_have_peers = !status.is_dev;
 	let (tx, rx) = tracing_unbounded("rpc_system_tests");
@@ -61,6 +70,9 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 						should_have_peers,
 					});
 				},
+				Request::BestBlockAge(sender) => {
+					let _ = sender.send(status.best_block_age);
+				},
 				Request::LocalPeerId(sender) => {
 					let _ = sender.send("QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".to_string());
 				},
@@ -90,6 +102,8 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 						connected_peers: Default::default(),
 						not_connected_peers: Default::default(),
 						peerset: serde_json::Value::Null,
+						total_bytes_inbound: 0,
+						total_bytes_outbound: 0,
 					}).unwrap());
 				},
 				Request::NetworkAddReservedPeer(peer, sender) => {
@@ -104,6 +118,9 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 						Err(s) => sender.send(Err(error::Error::MalformattedPeerArg(s.to_string()))),
 					};
 				}
+				Request::NetworkReservedPeers(sender) => {
+					let _ = sender.send(vec!["QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".to_string()]);
+				}
 				Request::NodeRoles(sender) => {
 					let _ = sender.send(vec![NodeRole::Authority]);
 				}
@@ -128,7 +145,8 @@ fn api<T: Into<Option<Status>>>(sync: T) -> System<Block> {
 			chain_type: Default::default(),
 		},
 		tx,
-		sc_rpc_api::DenyUnsafe::No
+		sc_rpc_api::DenyUnsafe::No,
+		max_block_age,
 	)
 }
 
@@ -194,6 +212,7 @@ fn system_health() {
 			peers: 5,
 			is_syncing: true,
 			is_dev: true,
+			..Default::default()
 		}).system_health()),
 		Health {
 			peers: 5,
@@ -208,6 +227,7 @@ fn system_health() {
 			peers: 5,
 			is_syncing: false,
 			is_dev: false,
+			..Default::default()
 		}).system_health()),
 		Health {
 			peers: 5,
@@ -222,6 +242,7 @@ fn system_health() {
 			peers: 0,
 			is_syncing: false,
 			is_dev: true,
+			..Default::default()
 		}).system_health()),
 		Health {
 			peers: 0,
@@ -231,6 +252,75 @@ fn system_health() {
 	);
 }
 
+#[test]
+fn system_ready() {
+	let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+
+	// no peers while peers are expected
+	assert!(runtime.block_on(api(None).system_ready()).is_err());
+
+	let syncing = api(Status {
+		peer_id: PeerId::random(),
+		peers: 5,
+		is_syncing: true,
+		is_dev: false,
+		..Default::default()
+	}).system_ready();
+	assert_matches!(
+		runtime.block_on(syncing),
+		Err(rpc::Error { data: Some(_), .. })
+	);
+
+	let ready = api(Status {
+		peer_id: PeerId::random(),
+		peers: 5,
+		is_syncing: false,
+		is_dev: false,
+		..Default::default()
+	}).system_ready();
+	assert_eq!(
+		runtime.block_on(ready).unwrap(),
+		Health {
+			peers: 5,
+			is_syncing: false,
+			should_have_peers: true,
+		}
+	);
+
+	// dev nodes are ready without peers
+	let dev = api(Status {
+		peer_id: PeerId::random(),
+		peers: 0,
+		is_syncing: false,
+		is_dev: true,
+		..Default::default()
+	}).system_ready();
+	assert!(runtime.block_on(dev).is_ok());
+}
+
+#[test]
+fn system_ready_checks_best_block_age() {
+	let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+	let status = |best_block_age| Status {
+		peers: 5,
+		best_block_age: Duration::from_secs(best_block_age),
+		..Default::default()
+	};
+
+	// the best block age is ignored without a maximum
+	assert!(runtime.block_on(api(status(600)).system_ready()).is_ok());
+
+	let max_block_age = Some(Duration::from_secs(60));
+	let recent = api_with_max_block_age(status(30), max_block_age).system_ready();
+	assert!(runtime.block_on(recent).is_ok());
+
+	let stale = api_with_max_block_age(status(600), max_block_age).system_ready();
+	assert_matches!(
+		runtime.block_on(stale),
+		Err(rpc::Error { code: rpc::ErrorCode::ServerError(2003), .. })
+	);
+}
+
 #[test]
 fn system_local_peer_id_works() {
 	assert_eq!(
@@ -260,6 +350,7 @@ fn system_peers() {
 		peers: 1,
 		is_syncing: false,
 		is_dev: true,
+		..Default::default()
 	}).system_peers();
 	let res = runtime.block_on(req).unwrap();
 
@@ -289,6 +380,8 @@ fn system_network_state() {
 			connected_peers: Default::default(),
 			not_connected_peers: Default::default(),
 			peerset: serde_json::Value::Null,
+			total_bytes_inbound: 0,
+			total_bytes_outbound: 0,
 		}
 	);
 }
@@ -337,6 +430,35 @@ fn system_network_remove_reserved() {
 	assert!(runtime.block_on(bad_fut).is_err());
 }
 
+#[test]
+fn system_network_reserved_peers() {
+	assert_eq!(
+		wait_receiver(api(None).system_reserved_peers()),
+		vec!["QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV".to_string()]
+	);
+}
+
+#[test]
+fn system_unsafe_calls_are_denied() {
+	let (tx, _rx) = tracing_unbounded("rpc_system_tests");
+	let api = System::<Block>::new(
+		SystemInfo {
+			impl_name: "testclient".into(),
+			impl_version: "0.2.0".into(),
+			chain_name: "testchain".into(),
+			properties: Default::default(),
+			chain_type: Default::default(),
+		},
+		tx,
+		sc_rpc_api::DenyUnsafe::Yes,
+	);
+	let mut runtime = tokio::runtime::current_thread::Runtime::new().unwrap();
+
+	let peer = "/ip4/198.51.100.19/tcp/30333/p2p/QmSk5HQbn6LhUwDiNMseVUjuRYhEtYj4aUZ6WfWoGURpdV";
+	assert!(runtime.block_on(api.system_add_reserved_peer(peer.into())).is_err());
+	assert!(runtime.block_on(api.system_network_state()).is_err());
+}
+
 #[test]
 fn test_add_reset_log_filter() {
 	const EXPECTED_BEFORE_ADD: &'static str = "EXPECTED_BEFORE_ADD";
@@ -74,6 +74,7 @@ use sc_client_api::{
 	execution_extensions::ExecutionExtensions
 };
 use sp_blockchain::{HeaderMetadata, HeaderBackend};
+use codec::{Decode, DecodeAll};
 
 /// A utility trait for building an RPC extension given a `DenyUnsafe` instance.
 /// This is useful since at service definition time we don't know whether the
@@ -297,6 +298,15 @@ pub fn new_full_client<TBl, TRtApi, TExecDisp>(
 	new_full_parts(config).map(|parts| parts.0)
 }
 
+/// Decode the banned block hashes given on the command line, rejecting any hash that is not
+/// exactly the size of the block hash.
+fn decode_banned_blocks<H: Decode>(hashes: &[Vec<u8>]) -> Result<Vec<H>, Error> {
+	hashes.iter()
+		.map(|hash| H::decode_all(&hash[..]))
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| Error::Other(format!("Invalid banned block hash: {:?}", e)))
+}
+
 /// Create the initial parts of a full node.
 pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
 	config: &Configuration,
@@ -322,10 +332,15 @@ pub fn new_full_parts<TBl, TRtApi, TExecDisp>(
 		.cloned()
 		.unwrap_or_default();
 
-	let bad_blocks = get_extension::<BadBlocks<TBl>>(chain_spec.extensions())
+	let mut bad_blocks = get_extension::<BadBlocks<TBl>>(chain_spec.extensions())
 		.cloned()
 		.unwrap_or_default();
 
+	if !config.banned_blocks.is_empty() {
+		let banned = decode_banned_blocks::<TBl::Hash>(&config.banned_blocks)?;
+		bad_blocks.get_or_insert_with(Default::default).extend(banned);
+	}
+
 	let (client, backend) = {
 		let db_config = sc_client_db::DatabaseSettings {
 			state_cache_size: config.state_cache_size,
@@ -787,7 +802,12 @@ fn gen_handler<TBl, TBackend, TExPool, TRpc, TCl>(
 		keystore,
 		deny_unsafe,
 	);
-	let system = system::System::new(system_info, system_rpc_tx, deny_unsafe);
+	let system = system::System::new(
+		system_info,
+		system_rpc_tx,
+		deny_unsafe,
+		config.rpc_ready_max_block_age,
+	);
 
 	let maybe_offchain_rpc = offchain_storage.map(|storage| {
 		let offchain = sc_rpc::offchain::Offchain::new(storage, deny_unsafe);
@@ -986,3 +1006,24 @@ impl NetworkStarter {
 		let _ = self.0.send(());
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+
+	#[test]
+	fn decode_banned_blocks_accepts_exact_length_hashes() {
+		let hash = H256::repeat_byte(0x42);
+		assert_eq!(
+			decode_banned_blocks::<H256>(&[hash.as_bytes().to_vec()]).unwrap(),
+			vec![hash],
+		);
+	}
+
+	#[test]
+	fn decode_banned_blocks_rejects_wrong_length_hashes() {
+		assert!(decode_banned_blocks::<H256>(&[vec![0x42; 31]]).is_err());
+		assert!(decode_banned_blocks::<H256>(&[vec![0x42; 33]]).is_err());
+	}
+}
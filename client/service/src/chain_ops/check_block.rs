@@ -29,6 +29,10 @@ use std::sync::Arc;
 use crate::chain_ops::import_blocks;
 
 /// Re-validate known block.
+///
+/// The block is read from the database and imported again even though it is already known, so it
+/// is re-executed on top of its parent state and import fails if the resulting state root doesn't
+/// match the one in its header.
 pub fn check_block<B, IQ, C>(
 	client: Arc<C>,
 	import_queue: IQ,
@@ -47,7 +51,7 @@ where
 			let reader = std::io::Cursor::new(buf);
 			import_blocks(client, import_queue, reader, true, true)
 		}
-		Ok(None) => Box::pin(future::err("Unknown block".into())),
+		Ok(None) => Box::pin(future::err(format!("Unknown block {:?}", block_id).into())),
 		Err(e) => Box::pin(future::err(format!("Error reading block: {:?}", e).into())),
 	}
 }
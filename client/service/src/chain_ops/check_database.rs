@@ -0,0 +1,211 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2021 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use log::{error, info};
+use sp_runtime::generic::BlockId;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Zero};
+use sp_blockchain::{Backend as BlockchainBackend, HeaderBackend};
+use sc_client_api::Backend;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Checks the block headers and the block number index of the database for inconsistencies.
+///
+/// Every leaf is walked back to genesis to make sure that no header is missing. Along the best
+/// chain, the block number index must point at the walked blocks. The finalized block must be
+/// part of the best chain and the state of the best block must be available.
+///
+/// Block bodies, the state of other blocks and consensus auxiliary data are not checked, and
+/// nothing is repaired.
+///
+/// All inconsistencies found are logged and an error is returned if there is any.
+pub fn check_database<B, BA>(backend: Arc<BA>) -> Result<(), Error>
+where
+	B: BlockT,
+	BA: Backend<B>,
+{
+	let blockchain = backend.blockchain();
+	let info = blockchain.info();
+	let mut problems = Vec::new();
+	let mut visited = HashSet::new();
+
+	let mut leaves = blockchain.leaves()?;
+	// walk the best chain first, so that the canonical index is checked along all of it.
+	leaves.retain(|leaf| *leaf != info.best_hash);
+	leaves.insert(0, info.best_hash);
+
+	for (index, leaf) in leaves.into_iter().enumerate() {
+		let canonical = index == 0;
+		let mut hash = leaf;
+
+		while visited.insert(hash) {
+			let header = match blockchain.header(BlockId::Hash(hash))? {
+				Some(header) => header,
+				None => {
+					problems.push(format!("Header of block {} is missing", hash));
+					break;
+				},
+			};
+
+			let number = *header.number();
+			if canonical && blockchain.hash(number)? != Some(hash) {
+				problems.push(format!(
+					"Block number index at #{} doesn't point at best chain block {}",
+					number,
+					hash,
+				));
+			}
+
+			if number.is_zero() {
+				if hash != info.genesis_hash {
+					problems.push(format!(
+						"Chain ends in block {} instead of genesis {}",
+						hash,
+						info.genesis_hash,
+					));
+				}
+				break;
+			}
+
+			hash = *header.parent_hash();
+		}
+	}
+
+	if info.finalized_number > info.best_number ||
+		blockchain.hash(info.finalized_number)? != Some(info.finalized_hash)
+	{
+		problems.push(format!(
+			"Finalized block #{} ({}) is not part of the best chain",
+			info.finalized_number,
+			info.finalized_hash,
+		));
+	}
+
+	if !backend.have_state_at(&info.best_hash, info.best_number) {
+		problems.push(format!(
+			"State of best block #{} ({}) is not available",
+			info.best_number,
+			info.best_hash,
+		));
+	}
+
+	if problems.is_empty() {
+		info!(
+			"Checked {} blocks. Best: #{} ({}), finalized: #{} ({})",
+			visited.len(),
+			info.best_number,
+			info.best_hash,
+			info.finalized_number,
+			info.finalized_hash,
+		);
+		Ok(())
+	} else {
+		for problem in &problems {
+			error!("{}", problem);
+		}
+		Err(Error::Other(format!("Found {} database inconsistencies", problems.len())))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sc_client_api::{in_mem, backend::{Backend as _, BlockImportOperation, NewBlockState}};
+	use sp_core::H256;
+	use substrate_test_runtime::{Block, Header};
+
+	fn header(number: u64, parent_hash: H256, fork: u64) -> Header {
+		Header::new(
+			number,
+			H256::from_low_u64_be(fork),
+			Default::default(),
+			parent_hash,
+			Default::default(),
+		)
+	}
+
+	fn insert_block(
+		backend: &in_mem::Backend<Block>,
+		header: Header,
+		state: NewBlockState,
+	) -> H256 {
+		let hash = header.hash();
+		let mut op = backend.begin_operation().unwrap();
+		backend.begin_state_operation(&mut op, BlockId::Hash(Default::default())).unwrap();
+		op.set_block_data(header, Some(Vec::new()), None, state).unwrap();
+		backend.commit_operation(op).unwrap();
+		hash
+	}
+
+	fn backend_with_genesis() -> (Arc<in_mem::Backend<Block>>, H256) {
+		let backend = Arc::new(in_mem::Backend::new());
+		let genesis = insert_block(&backend, header(0, Default::default(), 0), NewBlockState::Final);
+		(backend, genesis)
+	}
+
+	#[test]
+	fn accepts_consistent_database() {
+		let (backend, genesis) = backend_with_genesis();
+		let best_1 = insert_block(&backend, header(1, genesis, 0), NewBlockState::Best);
+		insert_block(&backend, header(2, best_1, 0), NewBlockState::Best);
+		insert_block(&backend, header(1, genesis, 1), NewBlockState::Normal);
+
+		assert!(check_database(backend).is_ok());
+	}
+
+	#[test]
+	fn reports_missing_header() {
+		let (backend, genesis) = backend_with_genesis();
+		insert_block(&backend, header(1, genesis, 0), NewBlockState::Best);
+		// a fork leaf whose parent was never imported.
+		insert_block(&backend, header(2, H256::repeat_byte(0x42), 1), NewBlockState::Normal);
+
+		assert!(check_database(backend).is_err());
+	}
+
+	#[test]
+	fn reports_bad_block_number_index() {
+		let (backend, genesis) = backend_with_genesis();
+		insert_block(&backend, header(1, genesis, 0), NewBlockState::Best);
+
+		// store a fork header under a hash that isn't its own. Making its child the best block
+		// points the block number index at the header's real hash instead.
+		let stored_hash = H256::repeat_byte(0x42);
+		backend.blockchain().insert(
+			stored_hash,
+			header(1, genesis, 1),
+			None,
+			None,
+			NewBlockState::Normal,
+		).unwrap();
+		insert_block(&backend, header(2, stored_hash, 1), NewBlockState::Best);
+
+		assert!(check_database(backend).is_err());
+	}
+
+	#[test]
+	fn reports_finalized_block_off_the_best_chain() {
+		let (backend, genesis) = backend_with_genesis();
+		insert_block(&backend, header(1, genesis, 0), NewBlockState::Best);
+		let fork = insert_block(&backend, header(1, genesis, 1), NewBlockState::Normal);
+		backend.finalize_block(BlockId::Hash(fork), None).unwrap();
+
+		assert!(check_database(backend).is_err());
+	}
+}
@@ -72,7 +72,7 @@ enum BlockIter<R, B> where
 		reader: CodecIoReader<R>,
 	},
 	Json {
-		// Nubmer of blocks we have decoded thus far.
+		// Number of blocks we have decoded thus far.
 		read_block_count: u64,
 		// Stream to the data, used for decoding new blocks.
 		reader: StreamDeserializer<'static, JsonIoRead<R>, SignedBlock<B>>,
@@ -284,6 +284,12 @@ enum ImportState<R, B> where
 }
 
 /// Starts the process of importing blocks.
+///
+/// Blocks are read from `input` either as a stream of JSON-encoded blocks or, if `binary` is set,
+/// as a SCALE-encoded block count followed by the SCALE-encoded blocks. At most
+/// `MAX_PENDING_BLOCKS` blocks are held in the import queue at any time. Blocks that are already
+/// in the chain are skipped unless `force` is set, so an interrupted import can be resumed by
+/// importing the same input again.
 pub fn import_blocks<B, IQ, C>(
 	client: Arc<C>,
 	mut import_queue: IQ,
@@ -19,12 +19,14 @@
 //! Chain utilities.
 
 mod check_block;
+mod check_database;
 mod export_blocks;
 mod export_raw_state;
 mod import_blocks;
 mod revert_chain;
 
 pub use check_block::*;
+pub use check_database::*;
 pub use export_blocks::*;
 pub use export_raw_state::*;
 pub use import_blocks::*;
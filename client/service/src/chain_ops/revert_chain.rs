@@ -22,24 +22,34 @@ use sp_runtime::traits::{Block as BlockT, NumberFor, Zero};
 use sc_client_api::{Backend, UsageProvider};
 use std::sync::Arc;
 
-/// Performs a revert of `blocks` blocks.
+/// Performs a revert of `blocks` blocks, returning the number of blocks actually reverted.
+///
+/// Finalized blocks are never reverted.
 pub fn revert_chain<B, BA, C>(
 	client: Arc<C>,
 	backend: Arc<BA>,
 	blocks: NumberFor<B>
-) -> Result<(), Error>
+) -> Result<NumberFor<B>, Error>
 where
 	B: BlockT,
 	C: UsageProvider<B>,
 	BA: Backend<B>,
 {
-	let reverted = backend.revert(blocks, false)?;
+	let (reverted, _) = backend.revert(blocks, false)?;
 	let info = client.usage_info().chain;
 
-	if reverted.0.is_zero() {
+	if reverted.is_zero() {
 		info!("There aren't any non-finalized blocks to revert.");
 	} else {
-		info!("Reverted {} blocks. Best: #{} ({})", reverted.0, info.best_number, info.best_hash);
+		info!(
+			"Reverted {} blocks. Best: #{} ({}), finalized: #{} ({})",
+			reverted,
+			info.best_number,
+			info.best_hash,
+			info.finalized_number,
+			info.finalized_hash,
+		);
 	}
-	Ok(())
+
+	Ok(reverted)
 }
@@ -30,7 +30,7 @@ use sc_client_api::{ForkBlocks, BadBlocks};
 pub enum LookupResult<B: BlockT> {
 	/// Specification rules do not contain any special rules about this block
 	NotSpecial,
-	/// The block is known to be bad and should not be imported
+	/// The block or its parent is known to be bad and should not be imported
 	KnownBad,
 	/// There is a specified canonical block hash for the given height
 	Expected(B::Hash)
@@ -62,15 +62,32 @@ impl<B: BlockT> BlockRules<B> {
 		self.bad.insert(hash);
 	}
 
+	/// Whether any block is known to be bad.
+	pub fn has_bad_blocks(&self) -> bool {
+		!self.bad.is_empty()
+	}
+
+	/// Whether the given block is known to be bad.
+	pub fn is_bad(&self, hash: &B::Hash) -> bool {
+		self.bad.contains(hash)
+	}
+
 	/// Check if there's any rule affecting the given block.
-	pub fn lookup(&self, number: NumberFor<B>, hash: &B::Hash) -> LookupResult<B> {
+	///
+	/// Blocks built on top of a known bad block are rejected as well.
+	pub fn lookup(
+		&self,
+		number: NumberFor<B>,
+		hash: &B::Hash,
+		parent_hash: &B::Hash,
+	) -> LookupResult<B> {
 		if let Some(hash_for_height) = self.forks.get(&number) {
 			if hash_for_height != hash {
 				return LookupResult::Expected(hash_for_height.clone());
 			}
 		}
 
-		if self.bad.contains(hash) {
+		if self.bad.contains(hash) || self.bad.contains(parent_hash) {
 			return LookupResult::KnownBad
 		}
 
@@ -23,12 +23,13 @@ use sp_runtime::{
 };
 use sp_state_machine::{
 	self, OverlayedChanges, Ext, ExecutionManager, StateMachine, ExecutionStrategy,
-	backend::Backend as _, StorageProof,
+	backend::Backend as _, StorageProof, StorageCollection,
 };
 use sc_executor::{RuntimeVersion, RuntimeInfo, NativeVersion};
 use sp_externalities::Extensions;
 use sp_core::{
-	NativeOrEncoded, NeverNativeValue, traits::{CodeExecutor, SpawnNamed, RuntimeCode},
+	NativeOrEncoded, NeverNativeValue, storage::well_known_keys,
+	traits::{CodeExecutor, SpawnNamed, RuntimeCode, WrappedRuntimeCode},
 };
 use sp_api::{ProofRecorder, InitializeBlock, StorageTransactionCache};
 use sc_client_api::{backend, call_executor::CallExecutor};
@@ -153,6 +154,70 @@ where
 		Ok(return_data.into_encoded())
 	}
 
+	fn call_with_overrides(
+		&self,
+		id: &BlockId<Block>,
+		method: &str,
+		call_data: &[u8],
+		overrides: StorageCollection,
+		strategy: ExecutionStrategy,
+		extensions: Option<Extensions>,
+	) -> sp_blockchain::Result<(Vec<u8>, StorageCollection)> {
+		let code_override = overrides.iter()
+			.find(|(key, _)| &key[..] == well_known_keys::CODE)
+			.and_then(|(_, code)| code.clone())
+			.map(|code| WrappedRuntimeCode(code.into()));
+
+		// Heap pages are read from the overrides like the backend reads them from the state.
+		let heap_pages_override = overrides.iter()
+			.find(|(key, _)| &key[..] == well_known_keys::HEAP_PAGES)
+			.map(|(_, heap_pages)| {
+				heap_pages.as_ref().and_then(|heap_pages| u64::decode(&mut &heap_pages[..]).ok())
+			});
+
+		let mut changes = OverlayedChanges::from_storage_overrides(overrides);
+
+		let changes_trie = backend::changes_tries_state_at_block(
+			id, self.backend.changes_trie_storage()
+		)?;
+		let state = self.backend.state_at(*id)?;
+		let state_runtime_code = sp_state_machine::backend::BackendRuntimeCode::new(&state);
+		let runtime_code = state_runtime_code.runtime_code()
+			.map_err(sp_blockchain::Error::RuntimeCode)?;
+		let mut runtime_code = match code_override.as_ref() {
+			Some(code_fetcher) => RuntimeCode {
+				code_fetcher,
+				heap_pages: runtime_code.heap_pages,
+				hash: <HashFor<Block> as sp_runtime::traits::Hash>::hash(&code_fetcher.0).encode(),
+			},
+			None => self.check_override(runtime_code, id)?,
+		};
+		if let Some(heap_pages) = heap_pages_override {
+			runtime_code.heap_pages = heap_pages;
+		}
+
+		let return_data = StateMachine::new(
+			&state,
+			changes_trie,
+			&mut changes,
+			&self.executor,
+			method,
+			call_data,
+			extensions.unwrap_or_default(),
+			&runtime_code,
+			self.spawn_handle.clone(),
+		).execute_using_consensus_failure_handler::<_, NeverNativeValue, fn() -> _>(
+			strategy.get_manager(),
+			None,
+		)?;
+
+		let changes = changes.changes()
+			.map(|(key, value)| (key.clone(), value.value().cloned()))
+			.collect();
+
+		Ok((return_data.into_encoded(), changes))
+	}
+
 	fn contextual_call<
 		'a,
 		IB: Fn() -> sp_blockchain::Result<()>,
@@ -1033,13 +1033,21 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 			}
 		};
 
-		if let Some(storage_changes) = notify_import.storage_changes {
-			// TODO [ToDr] How to handle re-orgs? Should we re-emit all storage changes?
+		let has_sinks = {
+			let mut import_notification_sinks = self.import_notification_sinks.lock();
+			import_notification_sinks.retain(|sink| !sink.is_closed());
+			!import_notification_sinks.is_empty()
+		};
+
+		// TODO [ToDr] How to handle re-orgs? Should we re-emit all storage changes?
+		let storage_changes = notify_import.storage_changes.map(Arc::new);
+		if let Some(storage_changes) = storage_changes.as_ref() {
+			// Storage listeners only get copies of the changes they are interested in.
 			self.storage_notifications.lock()
 				.trigger(
 					&notify_import.hash,
-					storage_changes.0.into_iter(),
-					storage_changes.1.into_iter().map(|(sk, v)| (sk, v.into_iter())),
+					storage_changes.0.iter().cloned(),
+					storage_changes.1.iter().map(|(sk, v)| (sk.clone(), v.iter().cloned())),
 				);
 		}
 
@@ -1049,6 +1057,8 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 			header: notify_import.header,
 			is_new_best: notify_import.is_new_best,
 			tree_route: notify_import.tree_route.map(Arc::new),
+			// The changes are only shared with import notifications if anyone receives them.
+			storage_changes: storage_changes.filter(|_| has_sinks),
 		};
 
 		self.import_notification_sinks.lock()
@@ -1088,6 +1098,35 @@ impl<B, E, Block, RA> Client<B, E, Block, RA> where
 		Ok(number)
 	}
 
+	/// Check whether a block built on the given parent descends from a known bad block.
+	///
+	/// Only the unfinalized ancestry of the parent is walked, a known bad block that is already
+	/// finalized can't be banned anymore.
+	fn has_bad_ancestor(&self, parent_hash: Block::Hash) -> sp_blockchain::Result<bool> {
+		if !self.block_rules.has_bad_blocks() {
+			return Ok(false)
+		}
+
+		let finalized_number = self.backend.blockchain().info().finalized_number;
+		let mut current = parent_hash;
+		loop {
+			let meta = match self.backend.blockchain().header_metadata(current) {
+				Ok(meta) => meta,
+				// the header of a queued parent isn't stored yet
+				Err(sp_blockchain::Error::UnknownBlock(_)) => return Ok(false),
+				Err(e) => return Err(e),
+			};
+
+			if self.block_rules.is_bad(&meta.hash) {
+				return Ok(true)
+			}
+			if meta.number <= finalized_number {
+				return Ok(false)
+			}
+			current = meta.parent;
+		}
+	}
+
 	/// Get blockchain info.
 	pub fn chain_info(&self) -> blockchain::Info<Block> {
 		self.backend.blockchain().info()
@@ -1723,7 +1762,7 @@ impl<B, E, Block, RA> sp_consensus::BlockImport<Block> for &Client<B, E, Block,
 
 		// Check the block against white and black lists if any are defined
 		// (i.e. fork blocks and bad blocks respectively)
-		match self.block_rules.lookup(number, &hash) {
+		match self.block_rules.lookup(number, &hash, &parent_hash) {
 			BlockLookupResult::KnownBad => {
 				trace!(
 					"Rejecting known bad block: #{} {:?}",
@@ -1766,6 +1805,18 @@ impl<B, E, Block, RA> sp_consensus::BlockImport<Block> for &Client<B, E, Block,
 				BlockStatus::KnownBad => return Ok(ImportResult::KnownBad),
 			}
 
+		// A known bad block may already be in the database, e.g. when it is banned from the
+		// command line after being imported, in which case its descendants are rejected too.
+		let has_bad_ancestor = self.has_bad_ancestor(parent_hash)
+			.map_err(|e| ConsensusError::ClientImport(e.to_string()))?;
+		if has_bad_ancestor {
+			trace!(
+				"Rejecting descendant of known bad block: #{} {:?}",
+				number,
+				hash,
+			);
+			return Ok(ImportResult::KnownBad);
+		}
 
 		Ok(ImportResult::imported(false))
 	}
@@ -89,7 +89,7 @@ pub enum WasmOverrideError {
 	#[error("Failed to get runtime version: {0}")]
 	VersionInvalid(String),
 
-	#[error("WASM override IO error")]
+	#[error("WASM override IO error in {}", .0.display())]
 	Io(PathBuf, #[source] std::io::Error),
 
 	#[error("Overwriting WASM requires a directory where local \
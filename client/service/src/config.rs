@@ -28,6 +28,7 @@ pub use sc_executor::WasmExecutionMethod;
 use sc_client_api::execution_extensions::ExecutionStrategies;
 
 use std::{io, future::Future, path::{PathBuf, Path}, pin::Pin, net::SocketAddr, sync::Arc};
+use std::time::Duration;
 pub use sc_transaction_pool::txpool::Options as TransactionPoolOptions;
 use sc_chain_spec::ChainSpec;
 use sp_core::crypto::SecretString;
@@ -75,6 +76,9 @@ pub struct Configuration {
 	/// over on-chain runtimes when the spec version matches. Set to `None` to
 	/// disable overrides (default).
 	pub wasm_runtime_overrides: Option<PathBuf>,
+	/// Encoded hashes of blocks that must not be imported, in addition to the bad blocks
+	/// of the chain specification.
+	pub banned_blocks: Vec<Vec<u8>>,
 	/// Execution strategies.
 	pub execution_strategies: ExecutionStrategies,
 	/// RPC over HTTP binding address. `None` if disabled.
@@ -89,6 +93,11 @@ pub struct Configuration {
 	pub rpc_cors: Option<Vec<String>>,
 	/// RPC methods to expose (by default only a safe subset or all of them).
 	pub rpc_methods: RpcMethods,
+	/// Maximum size of RPC requests, in megabytes. `None` if default. Responses are not limited.
+	pub rpc_max_payload: Option<usize>,
+	/// Maximum age of the best block for `system_ready` to report the node as ready. `None` if
+	/// the best block age isn't checked.
+	pub rpc_ready_max_block_age: Option<Duration>,
 	/// Prometheus endpoint configuration. `None` if disabled.
 	pub prometheus_config: Option<PrometheusConfig>,
 	/// Telemetry service URL. `None` if disabled.
@@ -203,6 +203,9 @@ async fn build_network_future<
 	// Current best block at initialization, to report to the RPC layer.
 	let starting_block = client.info().best_number;
 
+	// When the last new best block was imported, to report its age to the RPC layer.
+	let mut last_best_block_import = wasm_timer::Instant::now();
+
 	// Stream of finalized blocks reported by the client.
 	let mut finality_notification_stream = {
 		let mut finality_notification_stream = client.finality_notification_stream().fuse();
@@ -238,6 +241,7 @@ async fn build_network_future<
 				}
 
 				if notification.is_new_best {
+					last_best_block_import = wasm_timer::Instant::now();
 					network.service().new_best_block_imported(
 						notification.hash,
 						notification.header.number().clone(),
@@ -255,11 +259,14 @@ async fn build_network_future<
 				match request {
 					sc_rpc::system::Request::Health(sender) => {
 						let _ = sender.send(sc_rpc::system::Health {
-							peers: network.peers_debug_info().len(),
+							peers: network.num_connected_peers(),
 							is_syncing: network.service().is_major_syncing(),
 							should_have_peers,
 						});
 					},
+					sc_rpc::system::Request::BestBlockAge(sender) => {
+						let _ = sender.send(last_best_block_import.elapsed());
+					},
 					sc_rpc::system::Request::LocalPeerId(sender) => {
 						let _ = sender.send(network.local_peer_id().to_base58());
 					},
@@ -302,6 +309,13 @@ async fn build_network_future<
 							))),
 						};
 					}
+					sc_rpc::system::Request::NetworkReservedPeers(sender) => {
+						let reserved_peers = network.reserved_peers()
+							.map(|peer_id| peer_id.to_base58())
+							.collect();
+
+						let _ = sender.send(reserved_peers);
+					}
 					sc_rpc::system::Request::NodeRoles(sender) => {
 						use sc_rpc::system::NodeRole;
 
@@ -433,6 +447,7 @@ fn start_rpc_servers<
 					deny_unsafe(&address, &config.rpc_methods),
 					sc_rpc_server::RpcMiddleware::new(rpc_metrics.clone(), "http")
 				),
+				config.rpc_max_payload,
 			),
 		)?.map(|s| waiting::HttpServer(Some(s))),
 		maybe_start_server(
@@ -445,6 +460,7 @@ fn start_rpc_servers<
 					deny_unsafe(&address, &config.rpc_methods),
 					sc_rpc_server::RpcMiddleware::new(rpc_metrics.clone(), "ws")
 				),
+				config.rpc_max_payload,
 			),
 		)?.map(|s| waiting::WsServer(Some(s))),
 	)))
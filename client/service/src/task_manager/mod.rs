@@ -185,17 +185,17 @@ impl sp_core::traits::SpawnNamed for SpawnTaskHandle {
 
 /// A wrapper over `SpawnTaskHandle` that will notify a receiver whenever any
 /// task spawned through it fails. The service should be on the receiver side
-/// and will shut itself down whenever it receives any message, i.e. an
-/// essential task has failed.
+/// and will shut itself down whenever it receives any message, i.e. the name of
+/// an essential task that has failed.
 pub struct SpawnEssentialTaskHandle {
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<&'static str>,
 	inner: SpawnTaskHandle,
 }
 
 impl SpawnEssentialTaskHandle {
 	/// Creates a new `SpawnEssentialTaskHandle`.
 	pub fn new(
-		essential_failed_tx: TracingUnboundedSender<()>,
+		essential_failed_tx: TracingUnboundedSender<&'static str>,
 		spawn_task_handle: SpawnTaskHandle,
 	) -> SpawnEssentialTaskHandle {
 		SpawnEssentialTaskHandle {
@@ -233,7 +233,7 @@ impl SpawnEssentialTaskHandle {
 			.catch_unwind()
 			.map(move |_| {
 				log::error!("Essential task `{}` failed. Shutting down service.", name);
-				let _ = essential_failed.close_channel();
+				let _ = essential_failed.unbounded_send(name);
 			});
 
 		let _ = self.inner.spawn_inner(name, essential_task, task_type);
@@ -251,11 +251,11 @@ pub struct TaskManager {
 	executor: TaskExecutor,
 	/// Prometheus metric where to report the polling times.
 	metrics: Option<Metrics>,
-	/// Send a signal when a spawned essential task has concluded. The next time
+	/// Send the name of a spawned essential task when it has concluded. The next time
 	/// the service future is polled it should complete with an error.
-	essential_failed_tx: TracingUnboundedSender<()>,
+	essential_failed_tx: TracingUnboundedSender<&'static str>,
 	/// A receiver for spawned essential-tasks concluding.
-	essential_failed_rx: TracingUnboundedReceiver<()>,
+	essential_failed_rx: TracingUnboundedReceiver<&'static str>,
 	/// Things to keep alive until the task manager is dropped.
 	keep_alive: Box<dyn std::any::Any + Send + Sync>,
 	/// A sender to a stream of background tasks. This is used for the completion future.
@@ -366,7 +366,9 @@ impl TaskManager {
 			).fuse();
 
 			futures::select! {
-				_ = t1 => Err(Error::Other("Essential task failed.".into())),
+				name = t1 => Err(Error::Other(
+					format!("Essential task `{}` failed.", name.unwrap_or("<unknown>")),
+				)),
 				_ = t2 => Ok(()),
 				res = t3 => Err(res.map(|_| ()).expect_err("this future never ends; qed")),
 			}
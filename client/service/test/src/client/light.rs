@@ -55,7 +55,7 @@ use sp_blockchain::{
 };
 use std::panic::UnwindSafe;
 use std::cell::RefCell;
-use sp_state_machine::{OverlayedChanges, ExecutionManager};
+use sp_state_machine::{OverlayedChanges, ExecutionManager, StorageCollection};
 use parity_scale_codec::{Decode, Encode};
 use super::prepare_client_with_key_changes;
 use substrate_test_runtime_client::{
@@ -207,6 +207,18 @@ impl CallExecutor<Block> for DummyCallExecutor {
 		Ok(vec![42])
 	}
 
+	fn call_with_overrides(
+		&self,
+		_id: &BlockId<Block>,
+		_method: &str,
+		_call_data: &[u8],
+		_overrides: StorageCollection,
+		_strategy: ExecutionStrategy,
+		_extensions: Option<Extensions>,
+	) -> Result<(Vec<u8>, StorageCollection), ClientError> {
+		unreachable!()
+	}
+
 	fn contextual_call<
 		'a,
 		IB: Fn() -> ClientResult<()>,
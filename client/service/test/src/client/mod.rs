@@ -1360,6 +1360,73 @@ fn doesnt_import_blocks_that_revert_finality() {
 }
 
 
+#[test]
+fn rejects_descendants_of_bad_blocks() {
+	let bad_block = TestClientBuilder::new().build()
+		.new_block_at(&BlockId::Number(0), Default::default(), false)
+		.unwrap().build().unwrap().block;
+
+	let mut known_bad = HashSet::new();
+	known_bad.insert(bad_block.hash());
+	let mut client = TestClientBuilder::new()
+		.set_block_rules(None, Some(known_bad))
+		.build();
+
+	let params = BlockCheckParams {
+		hash: H256::repeat_byte(42),
+		number: 2,
+		parent_hash: bad_block.hash(),
+		allow_missing_state: false,
+		import_existing: false,
+	};
+	assert_eq!(client.check_block(params).unwrap(), ImportResult::KnownBad);
+}
+
+#[test]
+fn rejects_descendants_of_bad_blocks_already_in_the_database() {
+	let bad_block = TestClientBuilder::new().build()
+		.new_block_at(&BlockId::Number(0), Default::default(), false)
+		.unwrap().build().unwrap().block;
+
+	let mut known_bad = HashSet::new();
+	known_bad.insert(bad_block.hash());
+	let mut client = TestClientBuilder::new()
+		.set_block_rules(None, Some(known_bad))
+		.build();
+
+	// the bad block and two of its descendants were imported before it was banned
+	client.import(BlockOrigin::Own, bad_block).unwrap();
+	for number in 1..3 {
+		let block = client.new_block_at(&BlockId::Number(number), Default::default(), false)
+			.unwrap().build().unwrap().block;
+		client.import(BlockOrigin::Own, block).unwrap();
+	}
+
+	let block = client.new_block_at(&BlockId::Number(3), Default::default(), false)
+		.unwrap().build().unwrap().block;
+	let params = BlockCheckParams {
+		hash: block.hash(),
+		number: 4,
+		parent_hash: block.header().parent_hash().clone(),
+		allow_missing_state: false,
+		import_existing: false,
+	};
+	assert_eq!(client.check_block(params).unwrap(), ImportResult::KnownBad);
+
+	// a sibling of the bad block is still fine
+	let mut block = client.new_block_at(&BlockId::Number(0), Default::default(), false).unwrap();
+	block.push_storage_change(vec![0], Some(vec![1])).unwrap();
+	let block = block.build().unwrap().block;
+	let params = BlockCheckParams {
+		hash: block.hash(),
+		number: 1,
+		parent_hash: block.header().parent_hash().clone(),
+		allow_missing_state: false,
+		import_existing: false,
+	};
+	assert_eq!(client.check_block(params).unwrap(), ImportResult::imported(false));
+}
+
 #[test]
 fn respects_block_rules() {
 	fn run_test(
@@ -1852,3 +1919,78 @@ fn reorg_triggers_a_notification_even_for_sources_that_should_not_trigger_notifi
 	let tree_route = notification.tree_route.unwrap();
 	assert_eq!(tree_route.enacted()[0].hash, b1.hash());
 }
+
+#[test]
+fn custom_fork_choice_overrides_longest_chain() {
+	let mut client = TestClientBuilder::new().build();
+
+	let import_with_fork_choice = |client: &mut TestClient, block: Block, fork_choice| {
+		let (header, extrinsics) = block.deconstruct();
+		let mut import = BlockImportParams::new(BlockOrigin::Own, header);
+		import.body = Some(extrinsics);
+		import.fork_choice = Some(fork_choice);
+		client.import_block(import, Default::default()).unwrap();
+	};
+
+	let a1 = client.new_block_at(
+		&BlockId::Number(0),
+		Default::default(),
+		false,
+	).unwrap().build().unwrap().block;
+	client.import(BlockOrigin::Own, a1.clone()).unwrap();
+
+	let mut b1 = client.new_block_at(
+		&BlockId::Number(0),
+		Default::default(),
+		false,
+	).unwrap();
+	// needed to make sure B1 gets a different hash from A1
+	b1.push_transfer(Transfer {
+		from: AccountKeyring::Alice.into(),
+		to: AccountKeyring::Ferdie.into(),
+		amount: 1,
+		nonce: 0,
+	}).unwrap();
+	let b1 = b1.build().unwrap().block;
+	import_with_fork_choice(&mut client, b1.clone(), ForkChoiceStrategy::Custom(false));
+
+	let b2 = client.new_block_at(
+		&BlockId::Hash(b1.hash()),
+		Default::default(),
+		false,
+	).unwrap().build().unwrap().block;
+	import_with_fork_choice(&mut client, b2.clone(), ForkChoiceStrategy::Custom(false));
+
+	// the longer fork is not picked up when the engine rejects it
+	assert_eq!(client.chain_info().best_hash, a1.hash());
+
+	let b3 = client.new_block_at(
+		&BlockId::Hash(b2.hash()),
+		Default::default(),
+		false,
+	).unwrap().build().unwrap().block;
+	import_with_fork_choice(&mut client, b3.clone(), ForkChoiceStrategy::Custom(true));
+
+	assert_eq!(client.chain_info().best_hash, b3.hash());
+}
+
+#[test]
+fn import_notification_contains_storage_changes() {
+	let mut client = TestClientBuilder::new().build();
+
+	let mut notification_stream = futures::executor::block_on_stream(
+		client.import_notification_stream()
+	);
+
+	let mut builder = client.new_block(Default::default()).unwrap();
+	builder.push_storage_change(vec![1], Some(vec![42])).unwrap();
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block.clone()).unwrap();
+
+	let notification = notification_stream.next().unwrap();
+	assert_eq!(notification.hash, block.hash());
+	assert!(notification.is_new_best);
+
+	let storage_changes = notification.storage_changes.expect("block was executed on import");
+	assert!(storage_changes.0.contains(&(vec![1], Some(vec![42]))));
+}
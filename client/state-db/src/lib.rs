@@ -645,6 +645,26 @@ mod tests {
 		assert!(db.data_eq(&make_db(&[1, 21, 3, 921, 922, 93, 94])));
 	}
 
+	#[test]
+	fn pins_only_known_blocks() {
+		let (_, sdb) = make_test_db(PruningMode::Constrained(Constraints {
+			max_blocks: Some(1),
+			max_mem: None,
+		}));
+		// non-canonical and pruning window blocks can be pinned.
+		assert!(sdb.pin(&H256::from_low_u64_be(4)).is_ok());
+		assert!(sdb.pin(&H256::from_low_u64_be(3)).is_ok());
+		// pruned and unknown blocks can't.
+		assert!(matches!(sdb.pin(&H256::from_low_u64_be(1)), Err(PinError::InvalidBlock)));
+		assert!(matches!(sdb.pin(&H256::from_low_u64_be(100)), Err(PinError::InvalidBlock)));
+		sdb.unpin(&H256::from_low_u64_be(4));
+		sdb.unpin(&H256::from_low_u64_be(3));
+
+		// everything is kept in archive mode.
+		let (_, sdb) = make_test_db(PruningMode::ArchiveAll);
+		assert!(sdb.pin(&H256::from_low_u64_be(100)).is_ok());
+	}
+
 	#[test]
 	fn detects_incompatible_mode() {
 		let mut db = make_db(&[]);
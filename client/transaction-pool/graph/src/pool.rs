@@ -107,6 +107,8 @@ pub struct Options {
 	pub future: base::Limit,
 	/// Reject future transactions.
 	pub reject_future_transactions: bool,
+	/// Number of threads a full client validates transactions on, zero is treated as one.
+	pub validation_threads: usize,
 }
 
 impl Default for Options {
@@ -121,6 +123,7 @@ impl Default for Options {
 				total_bytes: 1 * 1024 * 1024,
 			},
 			reject_future_transactions: false,
+			validation_threads: 2,
 		}
 	}
 }
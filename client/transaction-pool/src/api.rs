@@ -50,6 +50,22 @@ impl<Client, Block> FullChainApi<Client, Block> {
 	pub fn new(
 		client: Arc<Client>,
 		prometheus: Option<&PrometheusRegistry>,
+	) -> Self {
+		Self::with_validation_threads(
+			client,
+			prometheus,
+			sc_transaction_graph::Options::default().validation_threads,
+		)
+	}
+
+	/// Create new transaction pool logic, validating transactions on the given number of threads.
+	///
+	/// Validation never runs on the caller's thread, so a flood of incoming transactions only
+	/// competes with itself for these threads.
+	pub fn with_validation_threads(
+		client: Arc<Client>,
+		prometheus: Option<&PrometheusRegistry>,
+		validation_threads: usize,
 	) -> Self {
 		let metrics = prometheus.map(ApiMetrics::register).and_then(|r| {
 			match r {
@@ -68,7 +84,7 @@ impl<Client, Block> FullChainApi<Client, Block> {
 		FullChainApi {
 			client,
 			pool: ThreadPoolBuilder::new()
-				.pool_size(2)
+				.pool_size(std::cmp::max(validation_threads, 1))
 				.name_prefix("txpool-verifier")
 				.create()
 				.expect("Failed to spawn verifier threads, that are critical for node operation."),
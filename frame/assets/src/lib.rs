@@ -749,8 +749,10 @@ pub mod pallet {
 				ensure!(&origin == &details.owner, Error::<T>::NoPermission);
 				if details.owner == owner { return Ok(().into()) }
 
-				// Move the deposit to the new owner.
-				T::Currency::repatriate_reserved(&details.owner, &owner, details.deposit, Reserved)?;
+				// Move the deposits to the new owner.
+				let metadata_deposit = Metadata::<T>::get(id).deposit;
+				let deposit = details.deposit.saturating_add(metadata_deposit);
+				T::Currency::repatriate_reserved(&details.owner, &owner, deposit, Reserved)?;
 
 				details.owner = owner.clone();
 
@@ -858,7 +860,7 @@ pub mod pallet {
 		/// - `symbol`: The exchange symbol for this asset. Limited in length by `StringLimit`.
 		/// - `decimals`: The number of decimals this asset uses to represent one unit.
 		///
-		/// Emits `MaxZombiesChanged`.
+		/// Emits `MetadataSet`.
 		///
 		/// Weight: `O(1)`
 		#[pallet::weight(T::WeightInfo::set_metadata(name.len() as u32, symbol.len() as u32))]
@@ -1494,6 +1496,13 @@ mod tests {
 			assert_ok!(Assets::transfer_ownership(Origin::signed(2), 0, 1));
 			assert_eq!(Balances::reserved_balance(&1), 11);
 			assert_eq!(Balances::reserved_balance(&2), 0);
+
+			// the metadata deposit is moved along.
+			assert_ok!(Assets::set_metadata(Origin::signed(1), 0, vec![0u8; 10], vec![0u8; 10], 12));
+			assert_eq!(Balances::reserved_balance(&1), 32);
+			assert_ok!(Assets::transfer_ownership(Origin::signed(1), 0, 2));
+			assert_eq!(Balances::reserved_balance(&2), 32);
+			assert_eq!(Balances::reserved_balance(&1), 0);
 		});
 	}
 
@@ -3,7 +3,7 @@
 use super::*;
 use crate as pallet_atomic_swap;
 
-use frame_support::parameter_types;
+use frame_support::{assert_noop, assert_ok, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -153,3 +153,39 @@ fn two_party_successful_swap() {
 		assert_eq!(Balances::free_balance(B), 200 + 50);
 	});
 }
+
+#[test]
+fn swap_can_only_be_cancelled_by_source_after_expiry() {
+	new_test_ext().execute_with(|| {
+		let proof: [u8; 2] = [4, 2];
+		let hashed_proof = blake2_256(&proof);
+
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			hashed_proof.clone(),
+			BalanceSwapAction::new(50),
+			10,
+		));
+		assert_eq!(Balances::free_balance(A), 100 - 50);
+
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(B), B, hashed_proof.clone()),
+			Error::<Test>::SourceMismatch,
+		);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof.clone()),
+			Error::<Test>::DurationNotPassed,
+		);
+
+		System::set_block_number(10);
+		assert_ok!(AtomicSwap::cancel_swap(Origin::signed(A), B, hashed_proof.clone()));
+		assert_eq!(Balances::free_balance(A), 100);
+
+		// the swap can't be claimed anymore.
+		assert_noop!(
+			AtomicSwap::claim_swap(Origin::signed(B), proof.to_vec(), BalanceSwapAction::new(50)),
+			Error::<Test>::InvalidProof,
+		);
+	});
+}
@@ -204,7 +204,7 @@ impl<T: Config> FindAuthor<u32> for Pallet<T> {
 		for (id, mut data) in digests.into_iter() {
 			if id == AURA_ENGINE_ID {
 				let slot = Slot::decode(&mut data).ok()?;
-				let author_index = *slot % Self::authorities().len() as u64;
+				let author_index = (*slot).checked_rem(Self::authorities().len() as u64)?;
 				return Some(author_index as u32)
 			}
 		}
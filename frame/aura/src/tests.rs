@@ -19,7 +19,10 @@
 
 #![cfg(test)]
 
-use crate::mock::{Aura, new_test_ext};
+use crate::mock::{Aura, Test, new_test_ext};
+use codec::Encode;
+use frame_support::traits::FindAuthor;
+use sp_consensus_aura::{AURA_ENGINE_ID, Slot};
 
 #[test]
 fn initial_values() {
@@ -28,3 +31,26 @@ fn initial_values() {
 		assert_eq!(Aura::authorities().len(), 4);
 	});
 }
+
+#[test]
+fn find_author_uses_slot_pre_digest() {
+	new_test_ext(vec![0, 1, 2, 3]).execute_with(|| {
+		let slot = Slot::from(5).encode();
+		let digests = vec![(AURA_ENGINE_ID, &slot[..])];
+
+		assert_eq!(Aura::find_author(digests.clone()), Some(1));
+		assert_eq!(
+			crate::AuraAuthorId::<Test>::find_author(digests),
+			Aura::authorities().get(1).cloned(),
+		);
+		assert_eq!(Aura::find_author(vec![(*b"test", &slot[..])]), None);
+	});
+}
+
+#[test]
+fn find_author_without_authorities_is_none() {
+	new_test_ext(vec![]).execute_with(|| {
+		let slot = Slot::from(5).encode();
+		assert_eq!(Aura::find_author(vec![(AURA_ENGINE_ID, &slot[..])]), None);
+	});
+}
@@ -673,6 +673,31 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn uncles_can_only_be_set_once_and_are_bounded() {
+		new_test_ext().execute_with(|| {
+			let uncle = seal_header(create_header(1, Default::default(), [1; 32].into()), 42);
+			let too_many = vec![uncle; MAX_UNCLES + 1];
+
+			assert_eq!(
+				Authorship::set_uncles(Origin::none(), too_many.clone()),
+				Err(Error::<Test>::TooManyUncles.into()),
+			);
+			assert!(
+				<Authorship as ProvideInherent>::check_inherent(
+					&Call::set_uncles(too_many),
+					&InherentData::new(),
+				).is_err()
+			);
+
+			assert_eq!(Authorship::set_uncles(Origin::none(), vec![]), Ok(()));
+			assert_eq!(
+				Authorship::set_uncles(Origin::none(), vec![]),
+				Err(Error::<Test>::UnclesAlreadySet.into()),
+			);
+		});
+	}
+
 	#[test]
 	fn sets_author_lazily() {
 		new_test_ext().execute_with(|| {
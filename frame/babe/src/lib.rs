@@ -344,6 +344,24 @@ impl<T: Config> RandomnessT<<T as frame_system::Config>::Hash> for Module<T> {
 	}
 }
 
+/// Randomness derived from the VRF outputs collected during the previous epoch.
+///
+/// The randomness provided by [`Module`] itself is the randomness of the *current* epoch, which
+/// was computed from the VRF outputs collected two epochs ago. This provides the randomness that
+/// will be used for the *next* epoch instead, which is fresher but was only fixed at the start of
+/// the current epoch. The same security caveats as for [`Module`] apply.
+pub struct RandomnessFromOneEpochAgo<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config> RandomnessT<<T as frame_system::Config>::Hash> for RandomnessFromOneEpochAgo<T> {
+	fn random(subject: &[u8]) -> T::Hash {
+		let mut subject = subject.to_vec();
+		subject.reserve(VRF_OUTPUT_LENGTH);
+		subject.extend_from_slice(&NextRandomness::get()[..]);
+
+		<T as frame_system::Config>::Hashing::hash(&subject[..])
+	}
+}
+
 /// A BABE public key
 pub type BabeKey = [u8; PUBLIC_KEY_LENGTH];
 
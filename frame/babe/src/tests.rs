@@ -190,6 +190,20 @@ fn no_author_vrf_output_for_secondary_plain() {
 	})
 }
 
+#[test]
+fn randomness_from_one_epoch_ago_uses_next_epoch_randomness() {
+	new_test_ext(1).execute_with(|| {
+		NextRandomness::put([1; RANDOMNESS_LENGTH]);
+		Randomness::put([2; RANDOMNESS_LENGTH]);
+
+		let expected = <Test as frame_system::Config>::Hashing::hash(
+			&[&b"subject"[..], &[1; RANDOMNESS_LENGTH][..]].concat(),
+		);
+		assert_eq!(RandomnessFromOneEpochAgo::<Test>::random(b"subject"), expected);
+		assert_ne!(Babe::random(b"subject"), expected);
+	})
+}
+
 #[test]
 fn authority_index() {
 	new_test_ext(4).execute_with(|| {
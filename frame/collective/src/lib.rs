@@ -868,6 +868,7 @@ where
 	}
 }
 
+/// Ensure that the origin is a single member of the collective. Returns the member's account.
 pub struct EnsureMember<AccountId, I=DefaultInstance>(sp_std::marker::PhantomData<(AccountId, I)>);
 impl<
 	O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
@@ -888,6 +889,8 @@ impl<
 	}
 }
 
+/// Ensure that the origin represents at least `N` members of the collective. Returns the number
+/// of approving members and the size of the collective.
 pub struct EnsureMembers<N: U32, AccountId, I=DefaultInstance>(sp_std::marker::PhantomData<(N, AccountId, I)>);
 impl<
 	O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
@@ -909,6 +912,7 @@ impl<
 	}
 }
 
+/// Ensure that the origin represents strictly more than the `N / D` proportion of the collective.
 pub struct EnsureProportionMoreThan<N: U32, D: U32, AccountId, I=DefaultInstance>(
 	sp_std::marker::PhantomData<(N, D, AccountId, I)>
 );
@@ -933,6 +937,7 @@ impl<
 	}
 }
 
+/// Ensure that the origin represents at least the `N / D` proportion of the collective.
 pub struct EnsureProportionAtLeast<N: U32, D: U32, AccountId, I=DefaultInstance>(
 	sp_std::marker::PhantomData<(N, D, AccountId, I)>
 );
@@ -963,7 +968,7 @@ mod tests {
 	use frame_support::{Hashable, assert_ok, assert_noop, parameter_types};
 	use frame_system::{self as system, EventRecord, Phase};
 	use hex_literal::hex;
-	use sp_core::H256;
+	use sp_core::{H256, u32_trait::{_2, _3}};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup}, testing::Header,
 		BuildStorage,
@@ -1069,6 +1074,39 @@ mod tests {
 		Call::System(frame_system::Call::remark(value.encode()))
 	}
 
+	#[test]
+	fn proportion_origins_check_thresholds() {
+		type AtLeastTwoThirds = EnsureProportionAtLeast<_2, _3, u64, Instance1>;
+		type MoreThanTwoThirds = EnsureProportionMoreThan<_2, _3, u64, Instance1>;
+		let members = |n, m| Origin::from(RawOrigin::<u64, Instance1>::Members(n, m));
+
+		assert!(AtLeastTwoThirds::try_origin(members(2, 3)).is_ok());
+		assert!(AtLeastTwoThirds::try_origin(members(1, 3)).is_err());
+		assert!(MoreThanTwoThirds::try_origin(members(3, 3)).is_ok());
+		assert!(MoreThanTwoThirds::try_origin(members(2, 3)).is_err());
+
+		// origins of other instances or modules are not accepted.
+		let other_instance = Origin::from(RawOrigin::<u64, Instance2>::Members(3, 3));
+		assert!(AtLeastTwoThirds::try_origin(other_instance).is_err());
+		assert!(AtLeastTwoThirds::try_origin(Origin::root()).is_err());
+	}
+
+	#[test]
+	fn member_origins_check_membership() {
+		type Member = EnsureMember<u64, Instance1>;
+		type AtLeastTwoMembers = EnsureMembers<_2, u64, Instance1>;
+		let member = |id| Origin::from(RawOrigin::<u64, Instance1>::Member(id));
+		let members = |n, m| Origin::from(RawOrigin::<u64, Instance1>::Members(n, m));
+
+		assert_eq!(Member::try_origin(member(42)).ok(), Some(42));
+		assert!(Member::try_origin(members(3, 3)).is_err());
+		assert!(Member::try_origin(Origin::signed(42)).is_err());
+
+		assert_eq!(AtLeastTwoMembers::try_origin(members(2, 5)).ok(), Some((2, 5)));
+		assert!(AtLeastTwoMembers::try_origin(members(1, 5)).is_err());
+		assert!(AtLeastTwoMembers::try_origin(member(42)).is_err());
+	}
+
 	#[test]
 	fn motions_basic_environment_works() {
 		new_test_ext().execute_with(|| {
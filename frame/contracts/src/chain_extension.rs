@@ -63,7 +63,7 @@ use sp_std::{
 pub use frame_system::Config as SysConfig;
 pub use pallet_contracts_primitives::ReturnFlags;
 pub use sp_core::crypto::UncheckedFrom;
-pub use crate::{Config, exec::Ext};
+pub use crate::{Config, exec::Ext, gas::ChargedAmount};
 pub use state::Init as InitState;
 
 /// Result that returns a [`DispatchError`] on error.
@@ -161,11 +161,27 @@ where
 	/// `weight`. It returns `Err` otherwise. In this case the chain extension should
 	/// abort the execution and pass through the error.
 	///
+	/// The returned value can be passed to [`adjust_weight`](Self::adjust_weight) in case
+	/// less weight than charged was actually consumed.
+	///
 	/// # Note
 	///
 	/// Weight is synonymous with gas in substrate.
-	pub fn charge_weight(&mut self, amount: Weight) -> Result<()> {
-		self.inner.runtime.charge_gas(RuntimeToken::ChainExtension(amount)).map(|_| ())
+	pub fn charge_weight(&mut self, amount: Weight) -> Result<ChargedAmount> {
+		self.inner.runtime.charge_gas(RuntimeToken::ChainExtension(amount))
+	}
+
+	/// Adjust a previously charged amount down to the `actual_weight` consumed.
+	///
+	/// This allows a chain extension to charge a worst case estimation before carrying out
+	/// an action and refund the difference afterwards. An `actual_weight` that is larger than
+	/// the charged amount is capped to it: charging more weight requires another call to
+	/// [`charge_weight`](Self::charge_weight).
+	pub fn adjust_weight(&mut self, charged: ChargedAmount, actual_weight: Weight) {
+		let actual_weight = actual_weight.min(charged.amount());
+		self.inner.runtime.refund_gas(charged);
+		// Can't fail: we just refunded at least `actual_weight`.
+		let _ = self.inner.runtime.charge_gas(RuntimeToken::ChainExtension(actual_weight));
 	}
 
 	/// Grants access to the execution environment of the current contract call.
@@ -483,7 +483,7 @@ where
 		// fail later due to tombstones not matching. This is because the restoration
 		// is always called from a contract and therefore in a storage transaction.
 		// The failure of this function will lead to this transaction's rollback.
-		let bytes_taken: u32 = delta.iter()
+		let (bytes_taken, pairs_taken) = delta.iter()
 			.filter_map(|key| {
 				let key = blake2_256(key);
 				child::get_raw(&child_trie_info, &key).map(|value| {
@@ -491,7 +491,9 @@ where
 					value.len() as u32
 				})
 			})
-			.sum();
+			.fold((0u32, 0u32), |(bytes_taken, pairs_taken), bytes| {
+				(bytes_taken.saturating_add(bytes), pairs_taken.saturating_add(1))
+			});
 
 		let tombstone = <TombstoneContractInfo<T>>::new(
 			// This operation is cheap enough because last_write (delta not included)
@@ -505,6 +507,7 @@ where
 		}
 
 		origin_contract.storage_size -= bytes_taken;
+		origin_contract.pair_count -= pairs_taken;
 
 		<ContractInfoOf<T>>::remove(&origin);
 		E::remove_user(origin_contract.code_hash);
@@ -179,6 +179,13 @@ impl ChainExtension<Test> for TestExtension {
 					data: vec![42, 99],
 				})
 			},
+			4 => {
+				let mut env = env.buf_in_buf_out();
+				let weight: Weight = env.read(2)?[1].into();
+				let charged = env.charge_weight(weight)?;
+				env.adjust_weight(charged, weight / 2);
+				Ok(RetVal::Converging(func_id))
+			},
 			_ => {
 				panic!("Passed unknown func_id to test chain extension: {}", func_id);
 			}
@@ -1374,6 +1381,7 @@ fn restoration(
 				let django_contract = ContractInfoOf::<Test>::get(&addr_django).unwrap()
 					.get_alive().unwrap();
 				assert_eq!(django_contract.storage_size, 8);
+				assert_eq!(django_contract.pair_count, 2);
 				assert_eq!(django_contract.trie_id, django_trie_id);
 				assert_eq!(django_contract.deduct_block, System::block_number());
 				assert_eq!(
@@ -1460,6 +1468,7 @@ fn restoration(
 					.get_alive().unwrap();
 				assert_eq!(bob_contract.rent_allowance, 50);
 				assert_eq!(bob_contract.storage_size, 4);
+				assert_eq!(bob_contract.pair_count, 1);
 				assert_eq!(bob_contract.trie_id, django_trie_id);
 				assert_eq!(bob_contract.deduct_block, System::block_number());
 				assert!(ContractInfoOf::<Test>::get(&addr_django).is_none());
@@ -2223,6 +2232,17 @@ fn chain_extension_works() {
 		).exec_result.unwrap();
 		assert_eq!(result.flags, ReturnFlags::REVERT);
 		assert_eq!(result.data, vec![42, 99]);
+
+		// 4 = charge some extra weight (amount supplied in second byte) and refund half of it
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			vec![4, 42],
+		);
+		assert_ok!(result.exec_result);
+		assert_eq!(result.gas_consumed, gas_consumed + 21);
 	});
 }
 
@@ -414,6 +414,11 @@ where
 		}
 	}
 
+	/// Refund gas previously charged through [`charge_gas`](Self::charge_gas).
+	pub fn refund_gas(&mut self, amount: ChargedAmount) {
+		self.gas_meter.refund(amount);
+	}
+
 	/// Read designated chunk from the sandbox memory.
 	///
 	/// Returns `Err` if one of the following conditions occurs:
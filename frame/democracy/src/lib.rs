@@ -851,7 +851,8 @@ decl_module! {
 		/// Delegate the voting power (with some given conviction) of the sending account.
 		///
 		/// The balance delegated is locked for as long as it's delegated, and thereafter for the
-		/// time appropriate for the conviction's lock period.
+		/// time appropriate for the conviction's lock period. This also applies when an existing
+		/// delegation is replaced by a new one.
 		///
 		/// The dispatch origin of this call must be _Signed_, and the signing account must either:
 		///   - be delegating already; or
@@ -1392,9 +1393,14 @@ impl<T: Config> Module<T> {
 			};
 			sp_std::mem::swap(&mut old, voting);
 			match old {
-				Voting::Delegating { balance, target, conviction, delegations, prior, .. } => {
+				Voting::Delegating { balance, target, conviction, delegations, mut prior, .. } => {
 					// remove any delegation votes to our current target.
 					Self::reduce_upstream_delegation(&target, conviction.votes(balance));
+					// the previous delegation stays locked for its conviction's lock period, just
+					// as if it had been undelegated.
+					let now = system::Module::<T>::block_number();
+					let lock_periods = conviction.lock_periods().into();
+					prior.accumulate(now + T::EnactmentPeriod::get() * lock_periods, balance);
 					voting.set_common(delegations, prior);
 				}
 				Voting::Direct { votes, delegations, prior } => {
@@ -362,3 +362,26 @@ fn locks_should_persist_from_delegation_to_voting() {
 		assert_eq!(Balances::locks(5), vec![]);
 	});
 }
+
+#[test]
+fn locks_should_persist_from_delegation_to_redelegation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(0);
+		assert_ok!(Democracy::delegate(Origin::signed(5), 1, Conviction::Locked5x, 20));
+		// redelegating with a lower conviction doesn't free the previous delegation.
+		assert_ok!(Democracy::delegate(Origin::signed(5), 1, Conviction::None, 10));
+		// locked 20 until #32
+
+		assert_ok!(Democracy::undelegate(Origin::signed(5)));
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5)[0].amount, 20);
+
+		fast_forward_to(31);
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5)[0].amount, 20);
+
+		fast_forward_to(32);
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5), vec![]);
+	});
+}
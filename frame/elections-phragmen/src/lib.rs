@@ -388,7 +388,7 @@ decl_module! {
 		///   - be less than the number of possible candidates. Note that all current members and
 		///     runners-up are also automatically candidates for the next round.
 		///
-		/// If `value` is more than `who`'s total balance, then the maximum of the two is used.
+		/// If `value` is more than `who`'s total balance, then the minimum of the two is used.
 		///
 		/// The dispatch origin of this call must be signed.
 		///
@@ -518,7 +518,7 @@ decl_module! {
 		///   origin is removed as a runner-up.
 		/// - `origin` is a current member. In this case, the deposit is unreserved and origin is
 		///   removed as a member, consequently not being a candidate for the next round anymore.
-		///   Similar to [`remove_members`], if replacement runners exists, they are immediately used.
+		///   Similar to [`remove_member`], if replacement runners exists, they are immediately used.
 		///   If the prime is renouncing, then no prime will exist until the next round.
 		///
 		/// The dispatch origin of this call must be signed, and have one of the above roles.
@@ -852,6 +852,32 @@ fn always_schedules_a_change_on_new_session_when_stalled() {
 	});
 }
 
+#[test]
+fn note_stalled_is_root_only_and_schedules_forced_change() {
+	new_test_ext(vec![(1, 1), (2, 1), (3, 1)]).execute_with(|| {
+		start_era(1);
+
+		assert_err!(
+			Grandpa::note_stalled(Origin::signed(1), 10, 1),
+			sp_runtime::DispatchError::BadOrigin,
+		);
+		assert!(Grandpa::stalled().is_none());
+
+		assert_ok!(Grandpa::note_stalled(Origin::root(), 10, 1));
+		assert_eq!(Grandpa::stalled(), Some((10, 1)));
+
+		// the forced change is only scheduled on the next session.
+		assert!(Grandpa::pending_change().is_none());
+
+		Grandpa::on_new_session(false, std::iter::empty(), std::iter::empty());
+
+		let pending_change = Grandpa::pending_change().unwrap();
+		assert_eq!(pending_change.forced, Some(1));
+		assert_eq!(pending_change.delay, 10);
+		assert!(Grandpa::stalled().is_none());
+	});
+}
+
 #[test]
 fn report_equivocation_has_valid_weight() {
 	// the weight depends on the size of the validator set,
@@ -619,7 +619,7 @@ decl_module! {
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
 		/// identity.
 		///
-		/// - `subs`: The identity's (new) sub-accounts.
+		/// - `subs`: The identity's (new) sub-accounts, which may not include the sender.
 		///
 		/// # <weight>
 		/// - `O(P + S)`
@@ -651,6 +651,7 @@ decl_module! {
 
 			let not_other_sub = subs.iter().filter_map(|i| SuperOf::<T>::get(&i.0)).all(|i| &i.0 == &sender);
 			ensure!(not_other_sub, Error::<T>::AlreadyClaimed);
+			ensure!(!subs.iter().any(|(sub, _)| sub == &sender), Error::<T>::InvalidTarget);
 
 			if old_deposit < new_deposit {
 				T::Currency::reserve(&sender, new_deposit - old_deposit)?;
@@ -1034,16 +1035,17 @@ decl_module! {
 
 		/// Add the given account to the sender's subs.
 		///
-		/// Payment: Balance reserved by a previous `set_subs` call for one sub will be repatriated
-		/// to the sender.
+		/// Payment: `SubAccountDeposit` will be reserved from the sender. The sender may not add
+		/// itself as a sub-account.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// sub identity of `sub`.
+		/// identity.
 		#[weight = T::WeightInfo::add_sub(T::MaxSubAccounts::get())]
 		fn add_sub(origin, sub: <T::Lookup as StaticLookup>::Source, data: Data) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let sub = T::Lookup::lookup(sub)?;
 			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(sub != sender, Error::<T>::InvalidTarget);
 
 			// Check if it's already claimed as sub-identity.
 			ensure!(!SuperOf::<T>::contains_key(&sub), Error::<T>::AlreadyClaimed);
@@ -1078,8 +1080,8 @@ decl_module! {
 
 		/// Remove the given account from the sender's subs.
 		///
-		/// Payment: Balance reserved by a previous `set_subs` call for one sub will be repatriated
-		/// to the sender.
+		/// Payment: Balance reserved by a previous `set_subs` or `add_sub` call for one sub will be
+		/// unreserved on the sender's account.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
 		/// sub identity of `sub`.
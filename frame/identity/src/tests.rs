@@ -161,6 +161,9 @@ fn editing_subaccounts_should_work() {
 
 		assert_ok!(Identity::set_identity(Origin::signed(10), ten()));
 
+		// cannot add self as sub account
+		assert_noop!(Identity::add_sub(Origin::signed(10), 10, data(1)), Error::<Test>::InvalidTarget);
+
 		// first sub account
 		assert_ok!(Identity::add_sub(Origin::signed(10), 1, data(1)));
 		assert_eq!(SuperOf::<Test>::get(1), Some((10, data(1))));
@@ -340,6 +343,13 @@ fn setting_subaccounts_should_work() {
 		assert_noop!(Identity::set_subs(Origin::signed(10), subs.clone()), Error::<Test>::NotFound);
 
 		assert_ok!(Identity::set_identity(Origin::signed(10), ten()));
+
+		// cannot set self as sub account
+		assert_noop!(
+			Identity::set_subs(Origin::signed(10), vec![(10, Data::Raw(vec![40; 1]))]),
+			Error::<Test>::InvalidTarget,
+		);
+
 		assert_ok!(Identity::set_subs(Origin::signed(10), subs.clone()));
 		assert_eq!(Balances::free_balance(10), 80);
 		assert_eq!(Identity::subs_of(10), (10, vec![20]));
@@ -123,6 +123,7 @@ type DecodeDifferentStr = DecodeDifferent<&'static str, StringBuf>;
 pub struct FunctionMetadata {
 	pub name: DecodeDifferentStr,
 	pub arguments: DecodeDifferentArray<FunctionArgumentMetadata>,
+	/// The doc comment lines of the function.
 	pub documentation: DecodeDifferentArray<&'static str, StringBuf>,
 }
 
@@ -182,6 +183,7 @@ pub struct OuterEventMetadata {
 pub struct EventMetadata {
 	pub name: DecodeDifferentStr,
 	pub arguments: DecodeDifferentArray<&'static str, StringBuf>,
+	/// The doc comment lines of the event variant.
 	pub documentation: DecodeDifferentArray<&'static str, StringBuf>,
 }
 
@@ -192,7 +194,9 @@ pub struct StorageEntryMetadata {
 	pub name: DecodeDifferentStr,
 	pub modifier: StorageEntryModifier,
 	pub ty: StorageEntryType,
+	/// The SCALE encoded value returned when the storage entry is empty.
 	pub default: ByteGetter,
+	/// The doc comment lines of the storage entry.
 	pub documentation: DecodeDifferentArray<&'static str, StringBuf>,
 }
 
@@ -202,7 +206,9 @@ pub struct StorageEntryMetadata {
 pub struct ModuleConstantMetadata {
 	pub name: DecodeDifferentStr,
 	pub ty: DecodeDifferentStr,
+	/// The SCALE encoded value of the constant.
 	pub value: ByteGetter,
+	/// The doc comment lines of the constant.
 	pub documentation: DecodeDifferentArray<&'static str, StringBuf>,
 }
 
@@ -211,6 +217,7 @@ pub struct ModuleConstantMetadata {
 #[cfg_attr(feature = "std", derive(Decode, Serialize))]
 pub struct ErrorMetadata {
 	pub name: DecodeDifferentStr,
+	/// The doc comment lines of the error variant.
 	pub documentation: DecodeDifferentArray<&'static str, StringBuf>,
 }
 
@@ -179,6 +179,7 @@ decl_event! {
 		AccountId = <T as frame_system::Config>::AccountId,
 		ProxyType = <T as Config>::ProxyType,
 		Hash = CallHashOf<T>,
+		BlockNumber = <T as frame_system::Config>::BlockNumber,
 	{
 		/// A proxy was executed correctly, with the given \[result\].
 		ProxyExecuted(DispatchResult),
@@ -187,6 +188,8 @@ decl_event! {
 		AnonymousCreated(AccountId, AccountId, ProxyType, u16),
 		/// An announcement was placed to make a call in the future. \[real, proxy, call_hash\]
 		Announced(AccountId, AccountId, Hash),
+		/// A proxy was added. \[delegator, delegatee, proxy_type, delay\]
+		ProxyAdded(AccountId, AccountId, ProxyType, BlockNumber),
 	}
 }
 
@@ -490,6 +493,7 @@ decl_module! {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// Parameters:
+		/// - `delegate`: The account that previously announced the call.
 		/// - `real`: The account that the proxy will make a call on behalf of.
 		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
 		/// - `call`: The call to be made by the `real` account.
@@ -572,7 +576,11 @@ impl<T: Config> Module<T> {
 		ensure!(delegator != &delegatee, Error::<T>::NoSelfProxy);
 		Proxies::<T>::try_mutate(delegator, |(ref mut proxies, ref mut deposit)| {
 			ensure!(proxies.len() < T::MaxProxies::get() as usize, Error::<T>::TooMany);
-			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
+			let proxy_def = ProxyDefinition {
+				delegate: delegatee.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+			};
 			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
 			proxies.insert(i, proxy_def);
 			let new_deposit = Self::deposit(proxies.len() as u32);
@@ -582,6 +590,7 @@ impl<T: Config> Module<T> {
 				T::Currency::unreserve(delegator, *deposit - new_deposit);
 			}
 			*deposit = new_deposit;
+			Self::deposit_event(RawEvent::ProxyAdded(delegator.clone(), delegatee, proxy_type, delay));
 			Ok(())
 		})
 	}
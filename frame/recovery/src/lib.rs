@@ -386,12 +386,18 @@ decl_module! {
 		/// - `rescuer`: The "rescuer account" which can call as the lost account.
 		///
 		/// # <weight>
+		/// - One storage read O(1)
 		/// - One storage write O(1)
 		/// - One event
 		/// # </weight>
 		#[weight = 0]
 		fn set_recovered(origin, lost: T::AccountId, rescuer: T::AccountId) {
 			ensure_root(origin)?;
+			// A rescuer which already acts for another account keeps the consumer reference it
+			// holds for that one.
+			if !Proxy::<T>::contains_key(&rescuer) {
+				system::Module::<T>::inc_consumers(&rescuer).map_err(|_| Error::<T>::BadState)?;
+			}
 			// Create the recovery storage item.
 			<Proxy<T>>::insert(&rescuer, &lost);
 			Self::deposit_event(RawEvent::AccountRecovered(lost, rescuer));
@@ -56,6 +56,22 @@ fn set_recovered_works() {
 	});
 }
 
+#[test]
+fn set_recovered_and_cancel_recovered_balance_consumers() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(frame_system::Module::<Test>::consumers(&1), 0);
+		assert_ok!(Recovery::set_recovered(Origin::root(), 5, 1));
+		assert_eq!(frame_system::Module::<Test>::consumers(&1), 1);
+		// Overriding the recovered account doesn't take another reference
+		assert_ok!(Recovery::set_recovered(Origin::root(), 4, 1));
+		assert_eq!(frame_system::Module::<Test>::consumers(&1), 1);
+		assert_ok!(Recovery::cancel_recovered(Origin::signed(1), 4));
+		assert_eq!(frame_system::Module::<Test>::consumers(&1), 0);
+		// Accounts which don't exist can't be set as rescuer
+		assert_noop!(Recovery::set_recovered(Origin::root(), 5, 42), Error::<Test>::BadState);
+	});
+}
+
 #[test]
 fn recovery_life_cycle_works() {
 	new_test_ext().execute_with(|| {
@@ -400,6 +400,33 @@ pub(crate) mod tests {
 		});
 	}
 
+	#[test]
+	fn proof_for_pruned_session_is_rejected() {
+		new_test_ext().execute_with(|| {
+			set_next_validators(vec![1, 2]);
+			force_new_session();
+
+			System::set_block_number(1);
+			Session::on_initialize(1);
+
+			let encoded_key_1 = UintAuthorityId(1).encode();
+			let proof = Historical::prove((DUMMY, &encoded_key_1[..])).unwrap();
+
+			force_new_session();
+
+			System::set_block_number(2);
+			Session::on_initialize(2);
+
+			assert!(Historical::check_proof((DUMMY, &encoded_key_1[..]), proof.clone()).is_some());
+
+			Historical::prune_up_to(proof.session + 1);
+			assert!(Historical::historical_root(proof.session).is_none());
+
+			// the session root is gone, so the proof can't be checked anymore.
+			assert!(Historical::check_proof((DUMMY, &encoded_key_1[..]), proof).is_none());
+		});
+	}
+
 	#[test]
 	fn prune_up_to_works() {
 		new_test_ext().execute_with(|| {
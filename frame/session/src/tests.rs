@@ -312,6 +312,29 @@ fn return_true_if_more_than_third_is_disabled() {
 	});
 }
 
+#[test]
+fn disabled_validators_are_reset_when_validator_set_changes() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+
+		assert_eq!(Session::disable(&2), Ok(false));
+		// disabling the same validator twice is a no-op.
+		assert_eq!(Session::disable(&2), Ok(false));
+		assert_eq!(Session::disable(&4), Err(()));
+		assert_eq!(Session::disabled_validators(), vec![1]);
+
+		// the genesis queued validator set is unchanged, so disabled validators are kept.
+		force_new_session();
+		initialize_block(1);
+		assert_eq!(Session::disabled_validators(), vec![1]);
+
+		// a new validator set was queued in the previous session and is now applied.
+		force_new_session();
+		initialize_block(2);
+		assert!(Session::disabled_validators().is_empty());
+	});
+}
+
 #[test]
 fn upgrade_keys() {
 	use frame_support::storage;
@@ -1183,6 +1183,9 @@ decl_event!(
 		Withdrawn(AccountId, Balance),
 		/// A nominator has been kicked from a validator. \[nominator, stash\]
 		Kicked(AccountId, AccountId),
+		/// A deferred slash of a validator (and its nominators) has been cancelled.
+		/// \[era_index, validator\]
+		SlashCancelled(EraIndex, AccountId),
 	}
 );
 
@@ -1950,6 +1953,8 @@ decl_module! {
 		///
 		/// Parameters: era and indices of the slashes for that era to kill.
 		///
+		/// Emits `SlashCancelled` for each of the cancelled slashes.
+		///
 		/// # <weight>
 		/// Complexity: O(U + S)
 		/// with U unapplied slashes weighted with U=1000
@@ -1970,7 +1975,8 @@ decl_module! {
 
 			for (removed, index) in slash_indices.into_iter().enumerate() {
 				let index = (index as usize) - removed;
-				unapplied.remove(index);
+				let slash = unapplied.remove(index);
+				Self::deposit_event(RawEvent::SlashCancelled(era, slash.validator));
 			}
 
 			<Self as Store>::UnappliedSlashes::insert(&era, &unapplied);
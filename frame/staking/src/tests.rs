@@ -2897,6 +2897,14 @@ fn remove_multi_deferred() {
 		);
 
 		assert_ok!(Staking::cancel_deferred_slash(Origin::root(), 1, vec![0, 2, 4]));
+		assert_eq!(
+			staking_events().into_iter().rev().take(3).rev().collect::<Vec<_>>(),
+			vec![
+				RawEvent::SlashCancelled(1, 11),
+				RawEvent::SlashCancelled(1, 11),
+				RawEvent::SlashCancelled(1, 69),
+			],
+		);
 
 		let slashes = <Staking as Store>::UnappliedSlashes::get(&1);
 		assert_eq!(slashes.len(), 2);
@@ -86,6 +86,11 @@
 //!	// same output to stdout, no overhead on WASM.
 //!	native::print!("My struct: {:?}", x);
 //! ```
+//!
+//! Log entries emitted through `RuntimeLogger` keep their level and target, and the node decides
+//! which of them to display based on its log filter. So logs of a given target can be enabled
+//! with e.g. `--log runtime=debug`, where `runtime` is the target passed to the `log` macro
+//! (`debug::info!(target: "runtime", ...)`).
 
 use sp_std::fmt::{self, Debug};
 
@@ -178,7 +183,7 @@ impl RuntimeLogger {
 		// final filtering on what should be printed.
 		//
 		// If we don't set any level, logging is disabled
-		// completly.
+		// completely.
 		log::set_max_level(log::LevelFilter::Trace);
 	}
 }
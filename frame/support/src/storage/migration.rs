@@ -146,7 +146,7 @@ impl<K: Decode + Sized, T: Decode + Sized, H: ReversibleStorageHasher> Iterator
 	}
 }
 
-/// Get a particular value in storage by the `module`, the map's `item` name and the key `hash`.
+/// Check a particular value is in storage by the `module`, the map's `item` name and the key `hash`.
 pub fn have_storage_value(module: &[u8], item: &[u8], hash: &[u8]) -> bool {
 	get_storage_value::<()>(module, item, hash).is_some()
 }
@@ -178,7 +178,7 @@ pub fn put_storage_value<T: Encode>(module: &[u8], item: &[u8], hash: &[u8], val
 	frame_support::storage::unhashed::put(&key, &value);
 }
 
-/// Get a particular value in storage by the `module`, the map's `item` name and the key `hash`.
+/// Remove all items under a storage prefix by the `module`, the map's `item` name and the key `hash`.
 pub fn remove_storage_prefix(module: &[u8], item: &[u8], hash: &[u8]) {
 	let mut key = vec![0u8; 32 + hash.len()];
 	key[0..16].copy_from_slice(&Twox128::hash(module));
@@ -187,7 +187,8 @@ pub fn remove_storage_prefix(module: &[u8], item: &[u8], hash: &[u8]) {
 	frame_support::storage::unhashed::kill_prefix(&key)
 }
 
-/// Get a particular value in storage by the `module`, the map's `item` name and the key `hash`.
+/// Take a particular item in storage by the `module`, the map's `item` name and the key `key`,
+/// hashed with `H`.
 pub fn take_storage_item<K: Encode + Sized, T: Decode + Sized, H: StorageHasher>(
 	module: &[u8],
 	item: &[u8],
@@ -195,3 +196,46 @@ pub fn take_storage_item<K: Encode + Sized, T: Decode + Sized, H: StorageHasher>
 ) -> Option<T> {
 	take_storage_value(module, item, key.using_encoded(H::hash).as_ref())
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Twox64Concat;
+	use sp_io::TestExternalities;
+
+	#[test]
+	fn storage_key_iterator_decodes_and_drains_keys() {
+		TestExternalities::default().execute_with(|| {
+			for i in 0u32..3 {
+				let hash = i.using_encoded(Twox64Concat::hash);
+				put_storage_value(b"Module", b"Map", &hash, i as u64 * 10);
+			}
+			put_storage_value(b"Module", b"Other", &[], 42u64);
+
+			// keys are iterated in raw storage key order, not in insertion order.
+			let mut items = StorageKeyIterator::<u32, u64, Twox64Concat>::new(b"Module", b"Map")
+				.collect::<Vec<_>>();
+			items.sort();
+			assert_eq!(items, vec![(0, 0), (1, 10), (2, 20)]);
+
+			let drained = StorageKeyIterator::<u32, u64, Twox64Concat>::new(b"Module", b"Map")
+				.drain()
+				.count();
+			assert_eq!(drained, 3);
+			assert_eq!(StorageIterator::<u64>::new(b"Module", b"Map").count(), 0);
+			assert_eq!(get_storage_value::<u64>(b"Module", b"Other", &[]), Some(42));
+		});
+	}
+
+	#[test]
+	fn take_storage_item_removes_the_value() {
+		TestExternalities::default().execute_with(|| {
+			let hash = 7u32.using_encoded(Twox64Concat::hash);
+			put_storage_value(b"Module", b"Map", &hash, 70u64);
+			assert!(have_storage_value(b"Module", b"Map", &hash));
+
+			assert_eq!(take_storage_item::<_, u64, Twox64Concat>(b"Module", b"Map", 7u32), Some(70));
+			assert!(!have_storage_value(b"Module", b"Map", &hash));
+		});
+	}
+}
@@ -217,3 +217,29 @@ fn transactional_annotation_in_decl_module() {
 		assert_noop!(<Module<Runtime>>::value_rollbacks(origin, 3), "nah");
 	});
 }
+
+#[test]
+fn nested_transactional_rollback_keeps_outer_changes() {
+	#[transactional]
+	fn inner_rollbacks(key: &str) -> DispatchResult {
+		Map::insert(key.to_string(), 2);
+		Value::set(2);
+		Err(DispatchError::Other("inner"))
+	}
+
+	#[transactional]
+	fn outer_commits() -> DispatchResult {
+		Value::set(1);
+		Map::insert("outer".to_string(), 1);
+		assert_noop!(inner_rollbacks("inner"), "inner");
+		Ok(())
+	}
+
+	TestExternalities::default().execute_with(|| {
+		assert_ok!(outer_commits());
+
+		assert_eq!(Value::get(), 1);
+		assert_eq!(Map::get("outer"), 1);
+		assert!(!Map::contains_key("inner"));
+	});
+}
@@ -119,4 +119,17 @@ mod tests {
 			assert_eq!(ext.validate(&1, CALL, &normal, len).unwrap().longevity, 15);
 		})
 	}
+
+	#[test]
+	fn signed_ext_check_era_immortal_should_use_genesis_hash() {
+		new_test_ext().execute_with(|| {
+			let normal = DispatchInfo { weight: 100, class: DispatchClass::Normal, pays_fee: Pays::Yes };
+			let ext = CheckMortality::<Test>::from(Era::immortal());
+			System::set_block_number(17);
+			<BlockHash<Test>>::insert(0, H256::repeat_byte(7));
+
+			assert_eq!(ext.additional_signed().unwrap(), H256::repeat_byte(7));
+			assert_eq!(ext.validate(&1, CALL, &normal, 0).unwrap().longevity, u64::max_value() - 17);
+		})
+	}
 }
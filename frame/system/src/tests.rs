@@ -382,6 +382,24 @@ fn set_code_with_real_wasm_blob() {
 	});
 }
 
+#[test]
+fn set_code_rejects_invalid_wasm_blob() {
+	let executor = substrate_test_runtime_client::new_native_executor();
+	let mut ext = new_test_ext();
+	ext.register_extension(sp_core::traits::CallInWasmExt::new(executor));
+	ext.execute_with(|| {
+		let res = System::set_code(
+			RawOrigin::Root.into(),
+			vec![1, 2, 3, 4],
+		);
+
+		assert_eq!(
+			Err(DispatchErrorWithPostInfo::from(Error::<Test>::FailedToExtractRuntimeVersion)),
+			res,
+		);
+	});
+}
+
 #[test]
 fn runtime_upgraded_with_set_storage() {
 	let executor = substrate_test_runtime_client::new_native_executor();
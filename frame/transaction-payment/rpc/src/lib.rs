@@ -19,7 +19,7 @@
 
 use std::sync::Arc;
 use std::convert::TryInto;
-use codec::{Codec, Decode};
+use codec::{Codec, DecodeAll};
 use sp_blockchain::HeaderBackend;
 use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
 use jsonrpc_derive::rpc;
@@ -100,7 +100,8 @@ where
 
 		let encoded_len = encoded_xt.len() as u32;
 
-		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+		// Trailing bytes would be accounted for in the length fee, so they are rejected.
+		let uxt: Block::Extrinsic = DecodeAll::decode_all(&*encoded_xt).map_err(|e| RpcError {
 			code: ErrorCode::ServerError(Error::DecodeError.into()),
 			message: "Unable to query dispatch info.".into(),
 			data: Some(format!("{:?}", e).into()),
@@ -125,7 +126,7 @@ where
 
 		let encoded_len = encoded_xt.len() as u32;
 
-		let uxt: Block::Extrinsic = Decode::decode(&mut &*encoded_xt).map_err(|e| RpcError {
+		let uxt: Block::Extrinsic = DecodeAll::decode_all(&*encoded_xt).map_err(|e| RpcError {
 			code: ErrorCode::ServerError(Error::DecodeError.into()),
 			message: "Unable to query fee details.".into(),
 			data: Some(format!("{:?}", e).into()),
@@ -508,7 +508,8 @@ impl<T: Config> ChargeTransactionPayment<T> where
 	/// Get an appropriate priority for a transaction with the given length and info.
 	///
 	/// This will try and optimise the `fee/weight` `fee/length`, whichever is consuming more of the
-	/// maximum corresponding limit.
+	/// maximum corresponding limit. The limits are the ones of the dispatch class of the
+	/// transaction.
 	///
 	/// For example, if a transaction consumed 1/4th of the block length and half of the weight, its
 	/// final priority is `fee * min(2, 4) = fee * 2`. If it consumed `1/4th` of the block length
@@ -516,8 +517,10 @@ impl<T: Config> ChargeTransactionPayment<T> where
 	///  that the transaction which consumes more resources (either length or weight) with the same
 	/// `fee` ends up having lower priority.
 	fn get_priority(len: usize, info: &DispatchInfoOf<T::Call>, final_fee: BalanceOf<T>) -> TransactionPriority {
-		let weight_saturation = T::BlockWeights::get().max_block / info.weight.max(1);
-		let max_block_length = *T::BlockLength::get().max.get(DispatchClass::Normal);
+		let weights = T::BlockWeights::get();
+		let max_block_weight = weights.get(info.class).max_total.unwrap_or(weights.max_block);
+		let weight_saturation = max_block_weight / info.weight.max(1);
+		let max_block_length = *T::BlockLength::get().max.get(info.class);
 		let len_saturation = max_block_length as u64 / (len as u64).max(1);
 		let coefficient: BalanceOf<T> = weight_saturation.min(len_saturation).saturated_into::<BalanceOf<T>>();
 		final_fee.saturating_mul(coefficient).saturated_into::<TransactionPriority>()
@@ -641,11 +644,16 @@ mod tests {
 
 	thread_local! {
 		static EXTRINSIC_BASE_WEIGHT: RefCell<u64> = RefCell::new(0);
+		static BLOCK_WEIGHTS_OVERRIDE: RefCell<Option<frame_system::limits::BlockWeights>> =
+			RefCell::new(None);
 	}
 
 	pub struct BlockWeights;
 	impl Get<frame_system::limits::BlockWeights> for BlockWeights {
 		fn get() -> frame_system::limits::BlockWeights {
+			if let Some(weights) = BLOCK_WEIGHTS_OVERRIDE.with(|v| v.borrow().clone()) {
+				return weights
+			}
 			frame_system::limits::BlockWeights::builder()
 				.base_block(0)
 				.for_class(DispatchClass::all(), |weights| {
@@ -1287,4 +1295,35 @@ mod tests {
 			assert_eq!(refund_based_fee, actual_fee);
 		});
 	}
+
+	#[test]
+	fn priority_uses_limits_of_dispatch_class() {
+		// Give each dispatch class distinct limits, and `max_block` a value different from all.
+		let weights = frame_system::limits::BlockWeights::builder()
+			.base_block(0)
+			.for_class(DispatchClass::Normal, |weights| {
+				weights.max_total = 1024.into();
+			})
+			.for_class(DispatchClass::Operational, |weights| {
+				weights.max_total = 1536.into();
+			})
+			.for_class(DispatchClass::Mandatory, |weights| {
+				weights.max_total = 2048.into();
+			})
+			.build_or_panic();
+		BLOCK_WEIGHTS_OVERRIDE.with(|v| *v.borrow_mut() = Some(weights));
+
+		ExtBuilder::default()
+			.build()
+			.execute_with(||
+		{
+			// Half of the `max_total` of the normal class, a quarter of `max_block`.
+			let normal = info_from_weight(512);
+			assert_eq!(ChargeTransactionPayment::<Runtime>::get_priority(1, &normal, 10), 20);
+
+			// A third of the `max_total` of the operational class.
+			let operational = DispatchInfo { class: DispatchClass::Operational, ..normal };
+			assert_eq!(ChargeTransactionPayment::<Runtime>::get_priority(1, &operational, 10), 30);
+		});
+	}
 }
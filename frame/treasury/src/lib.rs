@@ -217,6 +217,9 @@ decl_event!(
 		Rollover(Balance),
 		/// Some funds have been deposited. \[deposit\]
 		Deposit(Balance),
+		/// A proposal was approved and will be paid out at the next spend period.
+		/// \[proposal_index\]
+		Approved(ProposalIndex),
 	}
 );
 
@@ -321,6 +324,8 @@ decl_module! {
 
 			ensure!(<Proposals<T, I>>::contains_key(proposal_id), Error::<T, I>::InvalidIndex);
 			Approvals::<I>::append(proposal_id);
+
+			Self::deposit_event(RawEvent::Approved(proposal_id));
 		}
 
 		/// # <weight>
@@ -266,6 +266,22 @@ fn accepted_spend_proposal_enacted_on_spend_period() {
 	});
 }
 
+#[test]
+fn approve_proposal_deposits_event() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_ok!(Treasury::propose_spend(Origin::signed(0), 100, 3));
+		assert_ok!(Treasury::approve_proposal(Origin::root(), 0));
+
+		assert_eq!(
+			System::events().last().map(|r| r.event.clone()),
+			Some(Event::treasury(RawEvent::Approved(0))),
+		);
+	});
+}
+
 #[test]
 fn pot_underflow_should_not_diminish() {
 	new_test_ext().execute_with(|| {
@@ -59,6 +59,9 @@ pub enum Error {
 	#[error("UnknownBlock: {0}")]
 	UnknownBlock(String),
 
+	#[error("State already discarded for {0}")]
+	StateAlreadyDiscarded(String),
+
 	#[error(transparent)]
 	ApplyExtrinsicFailed(#[from] ApplyExtrinsicFailed),
 
@@ -31,6 +31,7 @@ pub type Seal = Vec<u8>;
 
 /// Define methods that total difficulty should implement.
 pub trait TotalDifficulty {
+	/// Add the difficulty of a newly imported block to the total, saturating on overflow.
 	fn increment(&mut self, other: Self);
 }
 
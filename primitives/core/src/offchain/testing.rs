@@ -379,7 +379,7 @@ impl offchain::Externalities for TestOffchainExt {
 				Ok(0)
 			} else {
 				let read = std::cmp::min(buffer.len(), response[req.read..].len());
-				buffer[0..read].copy_from_slice(&response[req.read..read]);
+				buffer[0..read].copy_from_slice(&response[req.read..req.read + read]);
 				req.read += read;
 				Ok(read)
 			}
@@ -427,3 +427,36 @@ impl TransactionPool for TestTransactionPoolExt {
 		Ok(())
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::offchain::Externalities;
+
+	#[test]
+	fn response_body_is_read_in_chunks() {
+		let (mut ext, state) = TestOffchainExt::new();
+		state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: "http://localhost:1234".into(),
+			sent: true,
+			response: Some(b"Hello, World!".to_vec()),
+			..Default::default()
+		});
+
+		let id = ext.http_request_start("GET", "http://localhost:1234", &[]).unwrap();
+		ext.http_request_write_body(id, &[], None).unwrap();
+		assert_eq!(ext.http_response_wait(&[id], None), vec![RequestStatus::Finished(200)]);
+
+		let mut body = Vec::new();
+		let mut buffer = [0u8; 4];
+		loop {
+			let read = ext.http_response_read_body(id, &mut buffer, None).unwrap();
+			if read == 0 {
+				break
+			}
+			body.extend_from_slice(&buffer[..read]);
+		}
+		assert_eq!(body, b"Hello, World!".to_vec());
+	}
+}
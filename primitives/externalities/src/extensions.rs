@@ -103,7 +103,7 @@ pub trait ExtensionStore {
 	/// It should return error if extension is already registered.
 	fn register_extension_with_type_id(&mut self, type_id: TypeId, extension: Box<dyn Extension>) -> Result<(), Error>;
 
-	/// Deregister extension with speicifed 'type_id' and drop it.
+	/// Deregister extension with specified 'type_id' and drop it.
 	///
 	/// It should return error if extension is not registered.
 	fn deregister_extension_by_type_id(&mut self, type_id: TypeId) -> Result<(), Error>;
@@ -129,6 +129,8 @@ impl Extensions {
 	}
 
 	/// Register the given extension.
+	///
+	/// An extension of the same type that was registered before is replaced.
 	pub fn register<E: Extension>(
 		&mut self,
 		ext: E,
@@ -192,4 +194,24 @@ mod tests {
 
 		assert_eq!(ext_ty.0, 1);
 	}
+
+	#[test]
+	fn register_with_type_id_rejects_duplicates_until_deregistered() {
+		let mut exts = Extensions::new();
+		let type_id = TypeId::of::<DummyExt>();
+
+		assert!(exts.register_with_type_id(type_id, Box::new(DummyExt(1))).is_ok());
+		assert!(matches!(
+			exts.register_with_type_id(type_id, Box::new(DummyExt(2))),
+			Err(Error::ExtensionAlreadyRegistered),
+		));
+
+		assert!(exts.deregister(type_id));
+		assert!(!exts.deregister(type_id));
+		assert!(exts.get_mut(type_id).is_none());
+
+		assert!(exts.register_with_type_id(type_id, Box::new(DummyExt(3))).is_ok());
+		let ext = exts.get_mut(type_id).expect("Extension is registered");
+		assert_eq!(ext.downcast_mut::<DummyExt>().expect("Downcasting works").0, 3);
+	}
 }
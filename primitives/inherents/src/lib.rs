@@ -478,6 +478,19 @@ mod tests {
 		assert!(data.put_data(TEST_INHERENT_0, &10).is_err());
 	}
 
+	#[test]
+	fn replacing_inherent_overwrites_data() {
+		let mut data = InherentData::new();
+		data.replace_data(TEST_INHERENT_0, &8u32);
+		data.replace_data(TEST_INHERENT_0, &10u32);
+
+		assert_eq!(data.len(), 1);
+		assert_eq!(data.get_data::<u32>(&TEST_INHERENT_0).unwrap(), Some(10));
+		assert_eq!(data.get_data::<u32>(&TEST_INHERENT_1).unwrap(), None);
+		// data that can't be decoded as the requested type is an error.
+		assert!(data.get_data::<u64>(&TEST_INHERENT_0).is_err());
+	}
+
 	#[derive(Clone)]
 	struct TestInherentDataProvider {
 		registered: Arc<RwLock<bool>>,
@@ -56,13 +56,14 @@ const DEN: ExtendedBalance = ExtendedBalance::max_value();
 ///   argument.
 /// - The returning weight distribution is _normalized_, meaning that it is guaranteed that the sum
 ///   of the ratios in each voter's distribution sums up to exactly `P::one()`.
+/// - The result only depends on the given inputs: candidates with equal scores in a round are
+///   broken in favour of the one that comes first in `initial_candidates`. Call sites that need
+///   all nodes to agree on the outcome must thus provide the candidates in a deterministic order.
 ///
-/// This can only fail of the normalization fails. This can happen if for any of the resulting
+/// This can only fail if the normalization fails. This can happen if for any of the resulting
 /// assignments, `assignment.distribution.map(|p| p.deconstruct()).sum()` fails to fit inside
 /// `UpperOf<P>`. A user of this crate may statically assert that this can never happen and safely
 /// `expect` this to return `Ok`.
-///
-/// This can only fail if the normalization fails.
 pub fn seq_phragmen<AccountId: IdentifierT, P: PerThing128>(
 	rounds: usize,
 	initial_candidates: Vec<AccountId>,
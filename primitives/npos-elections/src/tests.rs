@@ -332,6 +332,27 @@ fn phragmen_poc_works() {
 	);
 }
 
+#[test]
+fn phragmen_breaks_ties_by_candidate_order() {
+	let voters = vec![
+		(10, vec![1]),
+		(20, vec![2]),
+		(30, vec![3]),
+	];
+	let stake_of = create_stake_of(&[(10, 10), (20, 10), (30, 10)]);
+	let elect = |candidates: Vec<AccountId>| {
+		seq_phragmen::<_, Perbill>(
+			2,
+			candidates,
+			voters.iter().map(|(ref v, ref vs)| (v.clone(), stake_of(v), vs.clone())).collect::<Vec<_>>(),
+			None,
+		).unwrap().winners
+	};
+
+	assert_eq!(elect(vec![1, 2, 3]), vec![(1, 10), (2, 10)]);
+	assert_eq!(elect(vec![3, 2, 1]), vec![(3, 10), (2, 10)]);
+}
+
 #[test]
 fn phragmen_poc_works_with_balancing() {
 	let candidates = vec![1, 2, 3];
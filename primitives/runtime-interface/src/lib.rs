@@ -53,7 +53,7 @@
 //! To not waste any more performance when calling into the node, not all types are SCALE encoded
 //! when being passed as arguments between the wasm runtime and the node. For most types that
 //! are raw bytes like `Vec<u8>`, `[u8]` or `[u8; N]` we pass them directly, without SCALE encoding
-//! them in front of. The implementation of [`RIType`] each type provides more information on how
+//! them first. The implementation of [`RIType`] each type provides more information on how
 //! the data is passed.
 //!
 //! # Declaring a runtime interface
@@ -72,6 +72,10 @@
 //! For more information on declaring a runtime interface, see
 //! [`#[runtime_interface]`](./attr.runtime_interface.html).
 //!
+//! The macro also generates a `HostFunctions` type for the interface. Interfaces that are not part
+//! of `sp-io` need this type registered with the executor, e.g. through the `ExtendHostFunctions`
+//! of `sc_executor::native_executor_instance!`, so wasm runtimes can call them.
+//!
 //! # FFI type and conversion
 //!
 //! The following table documents how values of types are passed between the wasm and
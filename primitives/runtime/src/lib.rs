@@ -767,6 +767,23 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn dispatch_error_serialization() {
+		let error = DispatchError::Module {
+			index: 1,
+			error: 2,
+			message: Some("error message"),
+		};
+		assert_eq!(
+			serde_json::to_string(&error).unwrap(),
+			r#"{"Module":{"index":1,"error":2,"message":"error message"}}"#,
+		);
+
+		let decoded: DispatchError = serde_json::from_str(r#"{"Module":{"index":1,"error":2}}"#)
+			.unwrap();
+		assert_eq!(decoded, DispatchError::Module { index: 1, error: 2, message: None });
+	}
+
 	#[test]
 	fn multi_signature_ecdsa_verify_works() {
 		let msg = &b"test-message"[..];
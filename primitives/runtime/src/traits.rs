@@ -992,10 +992,10 @@ pub trait GetNodeBlockType {
 
 /// Something that can validate unsigned extrinsics for the transaction pool.
 ///
-/// Note that any checks done here are only used for determining the validity of
-/// the transaction for the transaction pool.
-/// During block execution phase one need to perform the same checks anyway,
-/// since this function is not being called.
+/// Note that any checks done in `validate_unsigned` are only used for determining the validity
+/// of the transaction for the transaction pool. During block execution `pre_dispatch` is called
+/// instead, which by default performs the same checks by calling `validate_unsigned` with
+/// `TransactionSource::InBlock`.
 pub trait ValidateUnsigned {
 	/// The call to validate
 	type Call;
@@ -1427,6 +1427,20 @@ mod tests {
 		let _ = s.verify(&[0u8; 100][..], &Public::default());
 	}
 
+	#[test]
+	fn trie_roots_use_the_selected_hasher() {
+		let keccak_empty: sp_core::H256 =
+			"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470".parse().unwrap();
+		assert_eq!(<Keccak256 as Hasher>::hash(&[]), keccak_empty);
+
+		// The empty trie is encoded as a single zero byte, whatever the hasher.
+		assert_eq!(Keccak256::trie_root(Vec::new()), <Keccak256 as Hasher>::hash(&[0u8]));
+		assert_eq!(BlakeTwo256::trie_root(Vec::new()), <BlakeTwo256 as Hasher>::hash(&[0u8]));
+
+		let input = vec![(b"key".to_vec(), b"value".to_vec())];
+		assert_ne!(Keccak256::trie_root(input.clone()), BlakeTwo256::trie_root(input));
+	}
+
 	#[derive(Encode, Decode, Default, PartialEq, Debug)]
 	struct U32Value(u32);
 	impl super::TypeId for U32Value {
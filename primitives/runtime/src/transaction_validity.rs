@@ -381,7 +381,7 @@ impl ValidTransactionBuilder {
 	/// Add a `TransactionTag` to the set of provided tags.
 	///
 	/// The tag will be encoded and prefixed with module prefix (if any).
-	/// If you'd rather add a raw `require` tag, consider using `#combine_with` method.
+	/// If you'd rather add a raw `provides` tag, consider using `#combine_with` method.
 	pub fn and_provides(mut self, tag: impl Encode) -> Self {
 		self.validity.provides.push(match self.prefix.as_ref() {
 			Some(prefix) => (prefix, tag).encode(),
@@ -392,7 +392,7 @@ impl ValidTransactionBuilder {
 
 	/// Augment the builder with existing `ValidTransaction`.
 	///
-	/// This method does add the prefix to `require` or `provides` tags.
+	/// This method does not add the prefix to `require` or `provides` tags.
 	pub fn combine_with(mut self, validity: ValidTransaction) -> Self {
 		self.validity = core::mem::take(&mut self.validity).combine_with(validity);
 		self
@@ -464,4 +464,28 @@ mod tests {
 			provides: vec![(PREFIX, 3).encode(), (PREFIX, 4).encode()],
 		});
 	}
+
+	#[test]
+	fn builder_should_not_prefix_combined_tags() {
+		const PREFIX: &str = "test";
+		let a: ValidTransaction = ValidTransaction::with_tag_prefix(PREFIX)
+			.and_provides(1)
+			.priority(3)
+			.longevity(10)
+			.combine_with(ValidTransaction {
+				priority: 2,
+				requires: vec![vec![7]],
+				provides: vec![vec![8]],
+				longevity: 5,
+				propagate: true,
+			})
+			.into();
+		assert_eq!(a, ValidTransaction {
+			propagate: true,
+			longevity: 5,
+			priority: 5,
+			requires: vec![vec![7]],
+			provides: vec![(PREFIX, 1).encode(), vec![8]],
+		});
+	}
 }
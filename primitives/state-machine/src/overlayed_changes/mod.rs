@@ -219,6 +219,17 @@ impl<Transaction: Default, H: Hasher, N: BlockNumber> Default for StorageChanges
 }
 
 impl OverlayedChanges {
+	/// Create new changes with the given top-level storage values set.
+	///
+	/// This is used to execute a call on top of some state with parts of it overridden.
+	pub fn from_storage_overrides(overrides: StorageCollection) -> Self {
+		let mut changes = Self::default();
+		for (key, value) in overrides {
+			changes.set_storage(key, value);
+		}
+		changes
+	}
+
 	/// Whether no changes are contained in the top nor in any of the child changes.
 	pub fn is_empty(&self) -> bool {
 		self.top.is_empty() && self.children.is_empty()
@@ -771,6 +782,18 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn from_storage_overrides_sets_the_overrides() {
+		let overlayed = OverlayedChanges::from_storage_overrides(vec![
+			(vec![1], Some(vec![2])),
+			(vec![3], None),
+		]);
+
+		assert_eq!(overlayed.storage(&[1]).unwrap(), Some(&[2][..]));
+		assert_eq!(overlayed.storage(&[3]).unwrap(), None);
+		assert!(overlayed.storage(&[4]).is_none());
+	}
+
 	#[test]
 	fn overlayed_storage_works() {
 		let mut overlayed = OverlayedChanges::default();
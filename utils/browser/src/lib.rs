@@ -36,7 +36,8 @@ use libp2p_wasm_ext::{ExtTransport, ffi};
 
 pub use console_error_panic_hook::set_once as set_console_error_panic_hook;
 
-/// Initialize the logger and return a `TelemetryWorker` and a wasm `ExtTransport`.
+/// Initialize the logger and return a `TelemetryWorker` sending telemetry over a wasm WebSocket
+/// `ExtTransport`.
 pub fn init_logging_and_telemetry(
 	pattern: &str,
 ) -> Result<sc_telemetry::TelemetryWorker, sc_tracing::logging::Error> {
@@ -112,6 +113,8 @@ where
 		rpc_ws: Default::default(),
 		rpc_ws_max_connections: Default::default(),
 		rpc_methods: Default::default(),
+		rpc_max_payload: Default::default(),
+		rpc_ready_max_block_age: Default::default(),
 		state_cache_child_ratio: Default::default(),
 		state_cache_size: Default::default(),
 		tracing_receiver: Default::default(),
@@ -119,6 +122,7 @@ where
 		transaction_pool: Default::default(),
 		wasm_method: Default::default(),
 		wasm_runtime_overrides: Default::default(),
+		banned_blocks: Default::default(),
 		max_runtime_instances: 8,
 		announce_block: true,
 		base_path: None,
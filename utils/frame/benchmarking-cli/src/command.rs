@@ -107,6 +107,13 @@ impl BenchmarkCmd {
 					crate::writer::write_results(&batches, output_path, self)?;
 				}
 
+				if self.json {
+					let json = serde_json::to_string_pretty(&batches_to_json(&batches))
+						.map_err(|e| format!("Failed to serialize benchmark results: {:?}", e))?;
+					println!("{}", json);
+					return Ok(())
+				}
+
 				for batch in batches.into_iter() {
 					// Print benchmark metadata
 					println!(
@@ -179,6 +186,37 @@ impl BenchmarkCmd {
 	}
 }
 
+/// Convert the benchmark results to a JSON value.
+fn batches_to_json(batches: &[BenchmarkBatch]) -> serde_json::Value {
+	let batches = batches.iter().map(|batch| {
+		let results = batch.results.iter().map(|result| {
+			let components = result.components.iter()
+				.map(|(param, value)| (param.to_string(), serde_json::Value::from(*value)))
+				.collect::<serde_json::Map<_, _>>();
+
+			serde_json::json!({
+				"components": components,
+				// `u128` is not supported by `serde_json` without the `arbitrary_precision` feature.
+				"extrinsic_time": result.extrinsic_time.to_string(),
+				"storage_root_time": result.storage_root_time.to_string(),
+				"reads": result.reads,
+				"repeat_reads": result.repeat_reads,
+				"writes": result.writes,
+				"repeat_writes": result.repeat_writes,
+			})
+		}).collect::<Vec<_>>();
+
+		serde_json::json!({
+			"pallet": String::from_utf8_lossy(&batch.pallet),
+			"instance": String::from_utf8_lossy(&batch.instance),
+			"benchmark": String::from_utf8_lossy(&batch.benchmark),
+			"results": results,
+		})
+	}).collect::<Vec<_>>();
+
+	serde_json::Value::Array(batches)
+}
+
 impl CliConfiguration for BenchmarkCmd {
 	fn shared_params(&self) -> &SharedParams {
 		&self.shared_params
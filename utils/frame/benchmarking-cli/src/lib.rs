@@ -52,6 +52,10 @@ pub struct BenchmarkCmd {
 	#[structopt(long = "raw")]
 	pub raw_data: bool,
 
+	/// Print the results as JSON instead of the human readable analysis.
+	#[structopt(long)]
+	pub json: bool,
+
 	/// Don't print the median-slopes linear regression analysis.
 	#[structopt(long)]
 	pub no_median_slopes: bool,
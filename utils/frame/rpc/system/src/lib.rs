@@ -332,6 +332,46 @@ mod tests {
 		assert_eq!(nonce.wait().unwrap(), 2);
 	}
 
+	#[test]
+	fn should_not_skip_nonce_gaps_in_the_pool() {
+		sp_tracing::try_init_simple();
+
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool = BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner,
+			client.clone(),
+		);
+
+		let source = sp_runtime::transaction_validity::TransactionSource::External;
+		let new_transaction = |nonce: u64| {
+			let t = Transfer {
+				from: AccountKeyring::Alice.into(),
+				to: AccountKeyring::Bob.into(),
+				amount: 5,
+				nonce,
+			};
+			t.into_signed_tx()
+		};
+		// Populate the pool with a ready and a future transaction
+		let ext0 = new_transaction(0);
+		block_on(pool.submit_one(&BlockId::number(0), source, ext0)).unwrap();
+		let ext2 = new_transaction(2);
+		block_on(pool.submit_one(&BlockId::number(0), source, ext2)).unwrap();
+
+		let accounts = FullSystem::new(client, pool, DenyUnsafe::Yes);
+
+		// when
+		let nonce = accounts.nonce(AccountKeyring::Alice.into());
+
+		// then
+		assert_eq!(nonce.wait().unwrap(), 1);
+	}
+
 	#[test]
 	fn dry_run_should_deny_unsafe() {
 		sp_tracing::try_init_simple();